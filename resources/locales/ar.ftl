@@ -0,0 +1,37 @@
+continue = متابعة
+done = تم
+you = أنت
+group-message = رسالة جماعية
+new-message = رسالة جديدة
+camera-access = جارٍ الوصول إلى كاميرا الجهاز.
+text-input-placeholder = أدخل النص...
+keyboard-space = مسافة
+keyboard-return = إدخال
+amount-minimum = الحد الأدنى هو { $min }
+amount-maximum = الحد الأقصى هو { $max }
+month-1 = يناير
+month-2 = فبراير
+month-3 = مارس
+month-4 = أبريل
+month-5 = مايو
+month-6 = يونيو
+month-7 = يوليو
+month-8 = أغسطس
+month-9 = سبتمبر
+month-10 = أكتوبر
+month-11 = نوفمبر
+month-12 = ديسمبر
+weekday-mon = ن
+weekday-tue = ث
+weekday-wed = ر
+weekday-thu = خ
+weekday-fri = ج
+weekday-sat = س
+weekday-sun = ح
+time-am = ص
+time-pm = م
+message-photo = صورة
+file-size-b = { $size } بايت
+file-size-kb = { $size } ك.ب
+file-size-mb = { $size } م.ب
+file-size-gb = { $size } ج.ب
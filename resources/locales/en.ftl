@@ -0,0 +1,38 @@
+# Strings shown by pelican_ui's built-in components.
+continue = Continue
+done = Done
+you = You
+group-message = Group Message
+new-message = New Message
+camera-access = Accessing device camera.
+text-input-placeholder = Enter text...
+keyboard-space = space
+keyboard-return = return
+amount-minimum = Minimum is { $min }
+amount-maximum = Maximum is { $max }
+month-1 = January
+month-2 = February
+month-3 = March
+month-4 = April
+month-5 = May
+month-6 = June
+month-7 = July
+month-8 = August
+month-9 = September
+month-10 = October
+month-11 = November
+month-12 = December
+weekday-mon = Mo
+weekday-tue = Tu
+weekday-wed = We
+weekday-thu = Th
+weekday-fri = Fr
+weekday-sat = Sa
+weekday-sun = Su
+time-am = AM
+time-pm = PM
+message-photo = Photo
+file-size-b = { $size } B
+file-size-kb = { $size } KB
+file-size-mb = { $size } MB
+file-size-gb = { $size } GB
@@ -0,0 +1,37 @@
+continue = המשך
+done = סיום
+you = את/ה
+group-message = הודעה קבוצתית
+new-message = הודעה חדשה
+camera-access = ניגש למצלמת המכשיר.
+text-input-placeholder = הזן טקסט...
+keyboard-space = רווח
+keyboard-return = אישור
+amount-minimum = המינימום הוא { $min }
+amount-maximum = המקסימום הוא { $max }
+month-1 = ינואר
+month-2 = פברואר
+month-3 = מרץ
+month-4 = אפריל
+month-5 = מאי
+month-6 = יוני
+month-7 = יולי
+month-8 = אוגוסט
+month-9 = ספטמבר
+month-10 = אוקטובר
+month-11 = נובמבר
+month-12 = דצמבר
+weekday-mon = ב׳
+weekday-tue = ג׳
+weekday-wed = ד׳
+weekday-thu = ה׳
+weekday-fri = ו׳
+weekday-sat = ש׳
+weekday-sun = א׳
+time-am = לפנה״צ
+time-pm = אחה״צ
+message-photo = תמונה
+file-size-b = { $size } בייט
+file-size-kb = { $size } ק״ב
+file-size-mb = { $size } מ״ב
+file-size-gb = { $size } ג״ב
@@ -0,0 +1,179 @@
+//! Semantics for assistive technology.
+//!
+//! Every interactive component implements [`Accessible`], describing itself as
+//! an [`AccessNode`]: a role plus label, value and state, mirroring AccessKit's
+//! node model. [`tree`] walks a live [`Interface`](crate::interface::general::Interface)
+//! and collects those nodes, so a screen reader can announce a `Bumper::stack`
+//! button as "Continue, button, disabled".
+//!
+//! With the `accesskit` feature, [`AccessNode::to_tree_update`] converts the tree
+//! into an `accesskit::TreeUpdate` for a platform adapter.
+
+use prism::drawable::Drawable;
+
+use crate::components::button::{PrimaryButton, SecondaryButton, SecondaryIconButton, GhostIconButton};
+use crate::components::text::{Text, ExpandableText};
+use crate::components::{Checkbox, DatePicker, TimePicker, RadioSelector, RichText, Slider, TextArea, TextInput, Toggle};
+
+/// The kind of widget a node represents; names follow AccessKit's `Role`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role { Window, Group, Button, CheckBox, Switch, RadioGroup, RadioButton, Slider, TextInput, DateInput, TimeInput, Label }
+
+impl Role {
+    fn announce(&self) -> Option<&'static str> {
+        match self {
+            Role::Window | Role::Group | Role::Label => None,
+            Role::Button => Some("button"),
+            Role::CheckBox => Some("checkbox"),
+            Role::Switch => Some("switch"),
+            Role::RadioGroup => Some("radio group"),
+            Role::RadioButton => Some("radio button"),
+            Role::Slider => Some("slider"),
+            Role::TextInput => Some("text field"),
+            Role::DateInput => Some("date picker"),
+            Role::TimeInput => Some("time picker"),
+        }
+    }
+}
+
+/// A single node of the accessibility tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessNode {
+    pub role: Role,
+    pub label: Option<String>,
+    pub value: Option<String>,
+    /// Current, minimum and maximum for range widgets such as [`Slider`].
+    pub numeric: Option<(f64, f64, f64)>,
+    pub checked: Option<bool>,
+    pub disabled: bool,
+    pub focused: bool,
+    pub error: Option<String>,
+    pub children: Vec<AccessNode>,
+}
+
+impl AccessNode {
+    pub fn new(role: Role) -> Self {
+        AccessNode { role, label: None, value: None, numeric: None, checked: None, disabled: false, focused: false, error: None, children: Vec::new() }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self { self.label = Some(label.into()); self }
+    pub fn value(mut self, value: impl Into<String>) -> Self { self.value = Some(value.into()); self }
+    pub fn numeric(mut self, value: f64, min: f64, max: f64) -> Self { self.numeric = Some((value, min, max)); self }
+    pub fn checked(mut self, checked: bool) -> Self { self.checked = Some(checked); self }
+    pub fn disabled(mut self, disabled: bool) -> Self { self.disabled = disabled; self }
+    pub fn focused(mut self, focused: bool) -> Self { self.focused = focused; self }
+    pub fn error(mut self, error: Option<String>) -> Self { self.error = error; self }
+    pub fn children(mut self, children: Vec<AccessNode>) -> Self { self.children = children; self }
+
+    /// What a screen reader would say on focus, e.g. `"Continue, button, disabled"`.
+    pub fn announce(&self) -> String {
+        let checked = self.checked.map(|c| match (self.role, c) {
+            (Role::Switch, true) => "on",
+            (Role::Switch, false) => "off",
+            (_, true) => "checked",
+            (_, false) => "not checked",
+        });
+
+        [self.label.as_deref(), self.role.announce(), self.value.as_deref(), checked, self.disabled.then_some("disabled"), self.error.as_deref()]
+            .into_iter().flatten().filter(|s| !s.is_empty()).collect::<Vec<_>>().join(", ")
+    }
+
+    /// Depth-first iterator over this node and all of its descendants.
+    pub fn iter(&self) -> impl Iterator<Item = &AccessNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+}
+
+/// Implemented by components that carry semantics.
+pub trait Accessible {
+    fn accessibility(&self) -> AccessNode;
+}
+
+macro_rules! accessible {
+    ($drawable:expr, $($ty:ty),* $(,)?) => {
+        $(if let Some(component) = $drawable.as_any().downcast_ref::<$ty>() {
+            return Some(component.accessibility());
+        })*
+    };
+}
+
+fn node(drawable: &dyn Drawable) -> Option<AccessNode> {
+    accessible!(drawable,
+        PrimaryButton, SecondaryButton, SecondaryIconButton, GhostIconButton,
+        Checkbox, RadioSelector, Toggle, Slider, TextInput, TextArea, DatePicker, TimePicker,
+        Text, ExpandableText, RichText,
+    );
+    None
+}
+
+fn collect(drawable: &dyn Drawable, nodes: &mut Vec<AccessNode>) {
+    match node(drawable) {
+        Some(node) => nodes.push(node),
+        None => drawable.children().into_iter().for_each(|child| collect(child, nodes)),
+    }
+}
+
+/// Builds the accessibility tree of `root`, usually the app's `Interface`.
+///
+/// Components without semantics are flattened away, so the tree holds only
+/// what a screen reader should announce, in reading order.
+pub fn tree(root: &dyn Drawable) -> AccessNode {
+    let mut nodes = Vec::new();
+    collect(root, &mut nodes);
+    AccessNode::new(Role::Window).children(nodes)
+}
+
+#[cfg(feature = "accesskit")]
+impl AccessNode {
+    /// Converts the tree into a full AccessKit update, numbering nodes depth first.
+    pub fn to_tree_update(&self) -> accesskit::TreeUpdate {
+        let mut nodes = Vec::new();
+        let root = self.push(&mut nodes, &mut 0);
+        let focus = self.iter().position(|node| node.focused).map(|i| accesskit::NodeId(i as u64)).unwrap_or(root);
+        accesskit::TreeUpdate { nodes, tree: Some(accesskit::Tree::new(root)), focus }
+    }
+
+    fn push(&self, nodes: &mut Vec<(accesskit::NodeId, accesskit::Node)>, next: &mut u64) -> accesskit::NodeId {
+        let id = accesskit::NodeId(*next);
+        *next += 1;
+
+        let mut node = accesskit::Node::new(match self.role {
+            Role::Window => accesskit::Role::Window,
+            Role::Group => accesskit::Role::Group,
+            Role::Button => accesskit::Role::Button,
+            Role::CheckBox => accesskit::Role::CheckBox,
+            Role::Switch => accesskit::Role::Switch,
+            Role::RadioGroup => accesskit::Role::RadioGroup,
+            Role::RadioButton => accesskit::Role::RadioButton,
+            Role::Slider => accesskit::Role::Slider,
+            Role::TextInput => accesskit::Role::TextInput,
+            Role::DateInput => accesskit::Role::DateInput,
+            Role::TimeInput => accesskit::Role::TimeInput,
+            Role::Label => accesskit::Role::Label,
+        });
+
+        if let Some(label) = &self.label { node.set_label(label.as_str()); }
+        if let Some(value) = &self.value { node.set_value(value.as_str()); }
+        if let Some((value, min, max)) = self.numeric {
+            node.set_numeric_value(value);
+            node.set_min_numeric_value(min);
+            node.set_max_numeric_value(max);
+        }
+        if let Some(checked) = self.checked {
+            node.set_toggled(if checked { accesskit::Toggled::True } else { accesskit::Toggled::False });
+        }
+        if self.disabled { node.set_disabled(); }
+        if self.error.is_some() { node.set_invalid(accesskit::Invalid::True); }
+
+        let index = nodes.len();
+        nodes.push((id, node));
+        let children = self.children.iter().map(|child| child.push(nodes, next)).collect::<Vec<_>>();
+        nodes[index].1.set_children(children);
+        id
+    }
+}
@@ -5,7 +5,7 @@ use prism::Context;
 use prism::drawable::{Component, SizedTree};
 
 use crate::Callback;
-use crate::theme::Theme;
+use crate::theme::{Theme, ThemeChanged, OnStatus};
 use crate::theme::Color;
 use crate::components::{Icon, Circle};
 
@@ -86,7 +86,10 @@ impl Avatar {
 
 impl OnEvent for Avatar {
     fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
-        if let Some(PickedPhoto(img)) = event.downcast_ref::<PickedPhoto>() && self.waiting_on_photo {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self._avatar = PrimaryAvatar::new(theme, self.content.clone(), self.outline, self._size);
+            self._flair = self.flair.map(|(name, style)| Flair::new(theme, name, style, self._size));
+        } else if let Some(PickedPhoto(img)) = event.downcast_ref::<PickedPhoto>() && self.waiting_on_photo {
             self.waiting_on_photo = false;
             self.content = AvatarContent::image(Arc::new(img.clone()));
         } else if let Some(MouseEvent{state: MouseState::Pressed, position: Some(_), button}) = event.downcast_ref::<MouseEvent>() {
@@ -322,9 +325,9 @@ impl AvatarIconStyle {
             AvatarIconStyle::Primary => (colors.get(ptsd::Outline::Primary), colors.get(ptsd::Background::Secondary)),
             AvatarIconStyle::Secondary => (colors.get(ptsd::Background::Secondary), colors.get(ptsd::Text::Secondary)),
             AvatarIconStyle::Brand => (colors.get(ptsd::Brand), colors.get(ptsd::Background::Primary)),
-            AvatarIconStyle::Success => (colors.get(ptsd::Status::Success), Color::WHITE),
-            AvatarIconStyle::Warning => (colors.get(ptsd::Status::Warning), Color::WHITE),
-            AvatarIconStyle::Danger => (colors.get(ptsd::Status::Danger), Color::WHITE),
+            AvatarIconStyle::Success => (colors.get(ptsd::Status::Success), colors.get(OnStatus::Success)),
+            AvatarIconStyle::Warning => (colors.get(ptsd::Status::Warning), colors.get(OnStatus::Warning)),
+            AvatarIconStyle::Danger => (colors.get(ptsd::Status::Danger), colors.get(OnStatus::Danger)),
         }
     }
 }
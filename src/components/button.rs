@@ -1,14 +1,16 @@
-use prism::event::OnEvent;
+use prism::event::{self, OnEvent, Event};
 use prism::{Context, drawables};
 use prism::canvas::{Image, Align};
-use prism::drawable::{Drawable, Component};
+use prism::drawable::{Drawable, Component, SizedTree};
 use prism::layout::{Wrap, Offset, Padding, Row, Size, Stack};
 
 use ptsd::interactions;
 use ptsd::theme::{Color, TextSize};
 
 use crate::Callback;
-use crate::theme::{self, Variant, Theme, ButtonColorScheme, Icons};
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::{FocusEvent, FocusRing};
+use crate::theme::{self, Variant, Theme, ButtonColorScheme, Icons, ThemeChanged};
 use crate::components::text::{Text, TextStyle};
 use crate::components::{Icon, Rectangle};
 
@@ -81,10 +83,35 @@ impl IconButtonGroup {
 /// let button = PrimaryButton::new(ctx, "Label", |ctx: &mut Context| println!("This button has been clicked!"), false);
 /// ```
 #[derive(Debug, Component, Clone)]
-pub struct PrimaryButton(Stack, pub interactions::Button);
-impl OnEvent for PrimaryButton {}
+pub struct PrimaryButton(Stack, pub interactions::Button, #[skip] String, #[skip] Box<dyn Callback>, #[skip] bool, FocusRing);
+impl OnEvent for PrimaryButton {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let (disabled, ring) = (self.4, self.5.clone());
+            *self = Self::build(theme, &self.2.clone(), self.3.clone());
+            (self.4, self.5) = (disabled, ring);
+        } else if let Some(event::Button::Disable(disabled)) = event.downcast_ref::<event::Button>() {
+            // Only primary buttons are built to respond to `Disable`.
+            self.4 = *disabled;
+        } else if let Some(FocusEvent::Activate) = event.downcast_ref::<FocusEvent>() && self.5.is_focused() && !self.4 {
+            (self.1.on_click())(ctx);
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for PrimaryButton {
+    fn accessibility(&self) -> AccessNode {
+        AccessNode::new(Role::Button).label(&self.2).disabled(self.4).focused(self.5.is_focused())
+    }
+}
+
 impl PrimaryButton {
-    pub fn new(theme: &Theme, label: &str, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+    pub fn new(theme: &Theme, label: &str, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+        Self::build(theme, label, Box::new(on_click))
+    }
+
+    fn build(theme: &Theme, label: &str, on_click: Box<dyn Callback>) -> Self {
         let colors = theme::Button::get(theme.colors(), Variant::Primary);
         let buttons = [colors.default, colors.hover, colors.pressed, colors.disabled];
         let [default, hover, pressed, disabled] = buttons.map(|colors| {
@@ -93,9 +120,9 @@ impl PrimaryButton {
             Button::new(drawables![text], ButtonSize::Large, ButtonWidth::Fill, Offset::Center, colors.background, colors.outline)
         });
         
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        PrimaryButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<Button>, callback, true))
+        let (theme, mut callback) = (theme.clone(), on_click.clone());
+        let callback = Box::new(move |ctx: &mut Context| (callback)(ctx, &theme));
+        PrimaryButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<Button>, callback, true), label.to_string(), on_click, false, FocusRing::outline(&theme, ButtonSize::Large.get().1 / 2.0))
     }
 
     pub fn default(theme: &Theme) -> Self { 
@@ -114,18 +141,47 @@ impl PrimaryButton {
 /// let button = SecondaryButton::medium(ctx, "edit", "Copy", Some("Copied"), |ctx: &mut Context| println!("This button has been clicked!"));
 /// ```
 #[derive(Debug, Component, Clone)]
-pub struct SecondaryButton(Stack, pub interactions::Button);
-impl OnEvent for SecondaryButton {}
+pub struct SecondaryButton(Stack, pub interactions::Button, #[skip] SecondaryContent, #[skip] Box<dyn Callback>, #[skip] bool, FocusRing);
+impl OnEvent for SecondaryButton {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let (disabled, ring) = (self.4, self.5.clone());
+            *self = match self.2.clone() {
+                SecondaryContent::Medium(icon, label, active) => Self::build_medium(theme, icon, &label, active.as_deref(), self.3.clone()),
+                SecondaryContent::Large(label) => Self::build_large(theme, &label, self.3.clone()),
+            };
+            (self.4, self.5) = (disabled, ring);
+        } else if let Some(FocusEvent::Activate) = event.downcast_ref::<FocusEvent>() && self.5.is_focused() && !self.4 {
+            (self.1.on_click())(ctx);
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for SecondaryButton {
+    fn accessibility(&self) -> AccessNode {
+        let label = match &self.2 {
+            SecondaryContent::Medium(_, label, _) | SecondaryContent::Large(label) => label,
+        };
+        AccessNode::new(Role::Button).label(label).disabled(self.4).focused(self.5.is_focused())
+    }
+}
+
 impl SecondaryButton {
-    pub fn medium(theme: &Theme, icon: Icons, label: &str, active_label: Option<&str>, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+    pub fn medium(theme: &Theme, icon: Icons, label: &str, active_label: Option<&str>, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+        Self::build_medium(theme, icon, label, active_label, Box::new(on_click))
+    }
+
+    fn build_medium(theme: &Theme, icon: Icons, label: &str, active_label: Option<&str>, on_click: Box<dyn Callback>) -> Self {
         let colors = theme::Button::get(theme.colors(), Variant::Secondary);
         let buttons = [colors.default, colors.hover, colors.pressed, colors.disabled];
         let [default, hover, pressed, disabled] = buttons.map(|colors| Self::_medium(theme, icon, label, colors));
         let feedback = active_label.map(|al| Self::_medium(theme, icon, al, colors.default));
 
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        SecondaryButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), feedback, callback, false))
+        let content = SecondaryContent::Medium(icon, label.to_string(), active_label.map(|al| al.to_string()));
+        let (theme, mut callback) = (theme.clone(), on_click.clone());
+        let callback = Box::new(move |ctx: &mut Context| (callback)(ctx, &theme));
+        SecondaryButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), feedback, callback, false), content, on_click, false, FocusRing::outline(&theme, ButtonSize::Medium.get().1 / 2.0))
     }
 
     fn _medium(theme: &Theme, icon: Icons, label: &str, colors: ButtonColorScheme) -> Button {
@@ -136,7 +192,11 @@ impl SecondaryButton {
         Button::new(drawables![icon, text], ButtonSize::Medium, ButtonWidth::Fit, Offset::Center, colors.background, colors.outline)
     }
 
-    pub fn large(theme: &Theme, label: &str,  mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+    pub fn large(theme: &Theme, label: &str, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+        Self::build_large(theme, label, Box::new(on_click))
+    }
+
+    fn build_large(theme: &Theme, label: &str, on_click: Box<dyn Callback>) -> Self {
         let colors = theme::Button::get(theme.colors(), Variant::Secondary);
         let buttons = [colors.default, colors.hover, colors.pressed, colors.disabled];
         let [default, hover, pressed, disabled] = buttons.map(|colors| {
@@ -145,9 +205,9 @@ impl SecondaryButton {
             Button::new(drawables![text], ButtonSize::Large, ButtonWidth::Fill, Offset::Center, colors.background, colors.outline)
         });
 
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        SecondaryButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<Button>, callback, false))
+        let (theme, mut callback) = (theme.clone(), on_click.clone());
+        let callback = Box::new(move |ctx: &mut Context| (callback)(ctx, &theme));
+        SecondaryButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<Button>, callback, false), SecondaryContent::Large(label.to_string()), on_click, false, FocusRing::outline(&theme, ButtonSize::Large.get().1 / 2.0))
     }
 
     pub fn default(theme: &Theme) -> Self { 
@@ -155,6 +215,13 @@ impl SecondaryButton {
     }
 }
 
+/// The icon and labels of a [`SecondaryButton`].
+#[derive(Debug, Clone)]
+enum SecondaryContent {
+    Medium(Icons, String, Option<String>),
+    Large(String),
+}
+
 /// ## Secondary Icon Button
 ///
 /// <img src="https://raw.githubusercontent.com/ramp-stack/pelican_ui_std/main/src/examples/secondary_icons.png"
@@ -166,31 +233,45 @@ impl SecondaryButton {
 /// let button = SecondaryIconButton::new(ctx, "info", |ctx: &mut Context| println!("This button has been clicked!"));
 /// ```
 #[derive(Debug, Component, Clone)]
-pub struct SecondaryIconButton(Stack, pub interactions::Button);
-impl OnEvent for SecondaryIconButton {}
+pub struct SecondaryIconButton(Stack, pub interactions::Button, #[skip] Icons, #[skip] ButtonSize, #[skip] Box<dyn Callback>, #[skip] bool, FocusRing);
+impl OnEvent for SecondaryIconButton {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let (disabled, ring) = (self.5, self.6.clone());
+            *self = Self::build(theme, self.2, self.3, self.4.clone());
+            (self.5, self.6) = (disabled, ring);
+        } else if let Some(FocusEvent::Activate) = event.downcast_ref::<FocusEvent>() && self.6.is_focused() && !self.5 {
+            (self.1.on_click())(ctx);
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for SecondaryIconButton {
+    fn accessibility(&self) -> AccessNode {
+        AccessNode::new(Role::Button).label(self.2.label()).disabled(self.5).focused(self.6.is_focused())
+    }
+}
+
 impl SecondaryIconButton {
-    pub fn large(theme: &Theme, icon: Icons, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
-        let colors = theme::Button::get(theme.colors(), Variant::Secondary);
-        let buttons = [colors.default, colors.hover, colors.pressed, colors.disabled];
-        let [default, hover, pressed, disabled] = buttons.map(|colors| {
-            IconButton::new(theme, icon, ButtonStyle::Secondary, ButtonSize::Large, colors.background, colors.outline, colors.label)
-        });
+    pub fn large(theme: &Theme, icon: Icons, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+        Self::build(theme, icon, ButtonSize::Large, Box::new(on_click))
+    }
 
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        SecondaryIconButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<IconButton>, callback, false))
+    pub fn medium(theme: &Theme, icon: Icons, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+        Self::build(theme, icon, ButtonSize::Medium, Box::new(on_click))
     }
 
-    pub fn medium(theme: &Theme, icon: Icons, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+    fn build(theme: &Theme, icon: Icons, size: ButtonSize, on_click: Box<dyn Callback>) -> Self {
         let colors = theme::Button::get(theme.colors(), Variant::Secondary);
         let buttons = [colors.default, colors.hover, colors.pressed, colors.disabled];
         let [default, hover, pressed, disabled] = buttons.map(|colors| {
-            IconButton::new(theme, icon, ButtonStyle::Secondary, ButtonSize::Medium, colors.background, colors.outline, colors.label)
+            IconButton::new(theme, icon, ButtonStyle::Secondary, size, colors.background, colors.outline, colors.label)
         });
 
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        SecondaryIconButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<IconButton>, callback, false))
+        let (theme, mut callback) = (theme.clone(), on_click.clone());
+        let callback = Box::new(move |ctx: &mut Context| (callback)(ctx, &theme));
+        SecondaryIconButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<IconButton>, callback, false), icon, size, on_click, false, FocusRing::outline(&theme, size.icon_button(ButtonStyle::Secondary).2))
     }
 
     pub fn default(theme: &Theme) -> Self { 
@@ -209,19 +290,41 @@ impl SecondaryIconButton {
 /// let button = GhostIconButton::new(ctx, "explore", |ctx: &mut Context| println!("This button has been clicked!"));
 /// ```
 #[derive(Debug, Component, Clone)]
-pub struct GhostIconButton(Stack, pub interactions::Button);
-impl OnEvent for GhostIconButton {}
+pub struct GhostIconButton(Stack, pub interactions::Button, #[skip] Icons, #[skip] Box<dyn Callback>, #[skip] bool, FocusRing);
+impl OnEvent for GhostIconButton {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let (disabled, ring) = (self.4, self.5.clone());
+            *self = Self::build(theme, self.2, self.3.clone());
+            (self.4, self.5) = (disabled, ring);
+        } else if let Some(FocusEvent::Activate) = event.downcast_ref::<FocusEvent>() && self.5.is_focused() && !self.4 {
+            (self.1.on_click())(ctx);
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for GhostIconButton {
+    fn accessibility(&self) -> AccessNode {
+        AccessNode::new(Role::Button).label(self.2.label()).disabled(self.4).focused(self.5.is_focused())
+    }
+}
+
 impl GhostIconButton {
-    pub fn new(theme: &Theme, icon: Icons, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+    pub fn new(theme: &Theme, icon: Icons, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+        Self::build(theme, icon, Box::new(on_click))
+    }
+
+    fn build(theme: &Theme, icon: Icons, on_click: Box<dyn Callback>) -> Self {
         let colors = theme::Button::get(theme.colors(), Variant::Ghost);
         let buttons = [colors.default, colors.hover, colors.pressed, colors.disabled];
         let [default, hover, pressed, disabled] = buttons.map(|colors| {
             IconButton::new(theme, icon, ButtonStyle::Ghost, ButtonSize::Medium, colors.background, colors.outline, colors.label)
         });
 
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        GhostIconButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<IconButton>, callback, false))
+        let (theme, mut callback) = (theme.clone(), on_click.clone());
+        let callback = Box::new(move |ctx: &mut Context| (callback)(ctx, &theme));
+        GhostIconButton(Stack::default(), interactions::Button::new(default, Some(hover), Some(pressed), Some(disabled), None::<IconButton>, callback, false), icon, on_click, false, FocusRing::outline(&theme, ButtonSize::Medium.icon_button(ButtonStyle::Ghost).2))
     }
 
     pub fn default(theme: &Theme) -> Self { 
@@ -1,26 +1,47 @@
-use prism::event::OnEvent;
+use prism::event::{OnEvent, Event};
 use prism::Context;
-use prism::drawable::Component;
+use prism::drawable::{Component, SizedTree};
 use prism::layout::{Stack, Column};
 
 use ptsd::interactions;
 
 use crate::Callback;
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::{self, FocusEvent, FocusRing};
 use crate::components::list_item::ListItem;
 use crate::components::list_item::ListItemInfoLeft;
-use crate::theme::{Theme, Icons};
+use crate::theme::{Theme, Icons, ThemeChanged};
 
 #[derive(Debug, Component, Clone)]
-pub struct Checkbox(Stack, pub interactions::Selectable);
-impl OnEvent for Checkbox {}
+pub struct Checkbox(Stack, pub interactions::Selectable, #[skip] String, FocusRing, #[skip] Option<String>, #[skip] (Box<dyn Callback>, Box<dyn Callback>));
+impl OnEvent for Checkbox {
+    fn on_event(&mut self, _ctx: &mut Context, sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(FocusEvent::Activate) = event.downcast_ref::<FocusEvent>() && self.3.is_focused() {
+            return [vec![event], focus::click_center(sized)].concat();
+        } else if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let (on_check, on_uncheck) = self.5.clone();
+            self.1 = Checkbox::selectable(theme, &self.2, self.4.as_deref(), self.1.is_selected(), on_check, on_uncheck);
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for Checkbox {
+    fn accessibility(&self) -> AccessNode {
+        AccessNode::new(Role::CheckBox).label(&self.2).checked(self.1.is_selected()).focused(self.3.is_focused())
+    }
+}
+
 impl Checkbox {
     pub fn new(theme: &Theme, title: &str, subtitle: Option<String>, is_selected: bool, on_check: Box<dyn Callback>, on_uncheck: Box<dyn Callback>) -> Self {
-        let selected = ListItem::new(theme, None, ListItemInfoLeft::new(title, subtitle.as_deref(), None, None), None, Some(Icons::Check), None, on_uncheck);
-        let default = ListItem::new(theme, None, ListItemInfoLeft::new(title, subtitle.as_deref(), None, None), None, Some(Icons::Unchecked), None, on_check);
-
-        let selectable = interactions::Selectable::new(default, selected, is_selected, true, Box::new(|_: &mut Context| {}), uuid::Uuid::new_v4());
+        let selectable = Self::selectable(theme, title, subtitle.as_deref(), is_selected, on_check.clone(), on_uncheck.clone());
+        Checkbox(Stack::default(), selectable, title.to_string(), FocusRing::outline(theme, 8.0), subtitle, (on_check, on_uncheck))
+    }
 
-        Checkbox(Stack::default(), selectable)
+    fn selectable(theme: &Theme, title: &str, subtitle: Option<&str>, is_selected: bool, on_check: Box<dyn Callback>, on_uncheck: Box<dyn Callback>) -> interactions::Selectable {
+        let selected = ListItem::new(theme, None, ListItemInfoLeft::new(title, subtitle, None, None), None, Some(Icons::Check), None, on_uncheck);
+        let default = ListItem::new(theme, None, ListItemInfoLeft::new(title, subtitle, None, None), None, Some(Icons::Unchecked), None, on_check);
+        interactions::Selectable::new(default, selected, is_selected, true, Box::new(|_: &mut Context| {}), uuid::Uuid::new_v4())
     }
 
     pub fn default(theme: &Theme) -> Self {
@@ -0,0 +1,364 @@
+use prism::event::{OnEvent, Event, TickEvent, KeyboardEvent, KeyboardState, Key, NamedKey};
+use prism::layout::{Stack, Column, Row, Size, Offset, Padding};
+use prism::drawable::{Drawable, Component, SizedTree};
+use prism::canvas::Align;
+use prism::Context;
+
+use ptsd::interactions;
+
+use chrono::{Datelike, Days, Local, Months, NaiveDate};
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::Shared;
+use crate::theme::{Theme, Icons, Color, ThemeChanged};
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::FocusRing;
+use crate::components::button::GhostIconButton;
+use crate::components::text::{Text, TextSize, TextStyle};
+use crate::components::Rectangle;
+use crate::locale::Directional;
+
+type Predicate = Arc<dyn Fn(NaiveDate) -> bool + Send + Sync>;
+
+/// ## Date Picker
+///
+/// A month grid for choosing a single day, with previous/next month buttons.
+/// When focused, the arrow keys move the selection by a day or a week and
+/// PageUp/PageDown by a month, skipping over days that can't be picked.
+///
+/// ### Example
+/// ```rust
+/// let picker = DatePicker::new(theme, None, |ctx: &mut Context, theme: &Theme, date: NaiveDate| {
+///     println!("Picked {date}");
+/// })
+/// .min(Local::now().date_naive())
+/// .disabled(|date| date.weekday() == Weekday::Sun);
+///
+/// let content = Content::new(Offset::Start, drawables![picker], Box::new(|_, children| DatePicker::validate(&children)));
+/// ```
+#[derive(Debug, Component, Clone)]
+pub struct DatePicker(Column, MonthHeader, Weekdays, Vec<Week>, FocusRing, #[skip] Arc<Mutex<Calendar>>, #[skip] OnDate, #[skip] Option<(NaiveDate, Option<NaiveDate>)>, #[skip] Theme);
+
+impl OnEvent for DatePicker {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.8 = theme.clone();
+            self.7 = None;
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(key), ..}) = event.downcast_ref::<KeyboardEvent>() && self.4.is_focused() {
+            let (back, forward) = match self.8.locale().is_rtl() {
+                true => (NamedKey::ArrowRight, NamedKey::ArrowLeft),
+                false => (NamedKey::ArrowLeft, NamedKey::ArrowRight),
+            };
+
+            let mut calendar = self.5.lock().unwrap();
+            let from = calendar.selected.unwrap_or(calendar.month);
+            let step: fn(NaiveDate) -> Option<NaiveDate> = match key {
+                k if *k == forward => |date| date.checked_add_days(Days::new(1)),
+                k if *k == back => |date| date.checked_sub_days(Days::new(1)),
+                NamedKey::ArrowDown => |date| date.checked_add_days(Days::new(7)),
+                NamedKey::ArrowUp => |date| date.checked_sub_days(Days::new(7)),
+                NamedKey::PageDown => |date| date.checked_add_months(Months::new(1)),
+                NamedKey::PageUp => |date| date.checked_sub_months(Months::new(1)),
+                _ => return vec![event],
+            };
+
+            if let Some(date) = calendar.seek(from, step) {
+                calendar.select(date);
+                drop(calendar);
+                self.6.call(ctx, &self.8, date);
+            }
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.refresh();
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for DatePicker {
+    fn accessibility(&self) -> AccessNode {
+        let node = AccessNode::new(Role::DateInput).label(&self.1.2.spans[0]).focused(self.4.is_focused());
+        match self.value() {
+            Some(date) => node.value(date.format("%Y-%m-%d").to_string()),
+            None => node,
+        }
+    }
+}
+
+impl DatePicker {
+    pub fn new(theme: &Theme, selected: Option<NaiveDate>, on_change: impl FnMut(&mut Context, &Theme, NaiveDate) + Send + Sync + 'static) -> Self {
+        let month = first_of(selected.unwrap_or_else(|| Local::now().date_naive()));
+        let calendar = Arc::new(Mutex::new(Calendar { month, selected, min: None, max: None, disabled: None }));
+        let header = MonthHeader::new(theme, &calendar);
+        let on_change = OnDate::new(on_change);
+
+        let mut picker = DatePicker(Column::center(8.0), header, Weekdays::new(theme), vec![], FocusRing::underline(theme), calendar, on_change, None, theme.clone());
+        picker.refresh();
+        picker
+    }
+
+    /// The earliest day that can be picked.
+    pub fn min(self, date: NaiveDate) -> Self {self.rule(|calendar| calendar.min = Some(date))}
+    /// The latest day that can be picked.
+    pub fn max(self, date: NaiveDate) -> Self {self.rule(|calendar| calendar.max = Some(date))}
+
+    /// Greys out and blocks every day for which `disabled` returns true.
+    pub fn disabled(self, disabled: impl Fn(NaiveDate) -> bool + Send + Sync + 'static) -> Self {
+        self.rule(|calendar| calendar.disabled = Some(Arc::new(disabled)))
+    }
+
+    pub fn value(&self) -> Option<NaiveDate> {self.5.lock().unwrap().selected}
+
+    /// Whether a day is selected and it satisfies the bounds and disabled days.
+    pub fn is_valid(&self) -> bool {
+        let calendar = self.5.lock().unwrap();
+        calendar.selected.is_some_and(|date| calendar.allows(date))
+    }
+
+    /// A [`Content`](crate::interface::general::Content) validation helper: true when
+    /// every `DatePicker` among `children` holds a valid date.
+    pub fn validate(children: &[&mut Box<dyn Drawable>]) -> bool {
+        children.iter().filter_map(|child| (***child).as_any().downcast_ref::<DatePicker>()).all(DatePicker::is_valid)
+    }
+
+    fn rule(mut self, apply: impl FnOnce(&mut Calendar)) -> Self {
+        apply(&mut self.5.lock().unwrap());
+        self.7 = None;
+        self.refresh();
+        self
+    }
+
+    /// Rebuilds the title and day grid when the shown month or the selection changed.
+    fn refresh(&mut self) {
+        let calendar = self.5.lock().unwrap().clone();
+        let shown = (calendar.month, calendar.selected);
+        if self.7 == Some(shown) { return; }
+
+        let theme = &self.8;
+        self.1.2.spans[0] = MonthHeader::title(theme, calendar.month);
+        self.3 = Week::month(theme, &calendar, &self.5, &self.6);
+        self.7 = Some(shown);
+    }
+}
+
+/// The month shown, the selection and the rules days are checked against.
+#[derive(Clone)]
+struct Calendar {
+    month: NaiveDate,
+    selected: Option<NaiveDate>,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+    disabled: Option<Predicate>,
+}
+
+impl Calendar {
+    /// How many steps keyboard navigation takes looking for an allowed day when
+    /// there is no bound to stop at.
+    const SEARCH: usize = 366;
+
+    fn allows(&self, date: NaiveDate) -> bool {
+        self.min.is_none_or(|min| date >= min)
+            && self.max.is_none_or(|max| date <= max)
+            && !self.disabled.as_ref().is_some_and(|disabled| (disabled)(date))
+    }
+
+    /// The first allowed day reached by repeatedly applying `step` to `from`, skipping
+    /// disabled days and giving up once it moves past `min` or `max`.
+    fn seek(&self, from: NaiveDate, step: fn(NaiveDate) -> Option<NaiveDate>) -> Option<NaiveDate> {
+        let mut date = from;
+        for _ in 0..Calendar::SEARCH {
+            date = step(date)?;
+            let passed = self.min.is_some_and(|min| date < min && date < from)
+                || self.max.is_some_and(|max| date > max && date > from);
+            if passed { return None; }
+            if self.allows(date) { return Some(date); }
+        }
+        None
+    }
+
+    fn select(&mut self, date: NaiveDate) {
+        self.selected = Some(date);
+        self.month = first_of(date);
+    }
+
+    /// Moves the shown month by `delta`, without leaving the months `min` and `max` fall in.
+    fn step(&mut self, delta: i32) {
+        let month = match delta < 0 {
+            true => self.month.checked_sub_months(Months::new(delta.unsigned_abs())),
+            false => self.month.checked_add_months(Months::new(delta as u32)),
+        };
+        if let Some(month) = month
+            && self.min.is_none_or(|min| month >= first_of(min))
+            && self.max.is_none_or(|max| month <= max) {
+            self.month = month;
+        }
+    }
+}
+
+impl fmt::Debug for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Calendar")
+            .field("month", &self.month)
+            .field("selected", &self.selected)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("disabled", &self.disabled.is_some())
+            .finish()
+    }
+}
+
+type OnDate = Shared<dyn FnMut(&mut Context, &Theme, NaiveDate) + Send + Sync>;
+
+impl OnDate {
+    fn new(callback: impl FnMut(&mut Context, &Theme, NaiveDate) + Send + Sync + 'static) -> Self {Shared(Arc::new(Mutex::new(callback)))}
+    fn call(&self, ctx: &mut Context, theme: &Theme, date: NaiveDate) {(self.lock())(ctx, theme, date)}
+}
+
+fn first_of(date: NaiveDate) -> NaiveDate {date.with_day(1).unwrap_or(date)}
+
+#[derive(Debug, Component, Clone)]
+struct MonthHeader(Directional, GhostIconButton, Text, GhostIconButton);
+impl OnEvent for MonthHeader {}
+
+impl MonthHeader {
+    fn new(theme: &Theme, calendar: &Arc<Mutex<Calendar>>) -> Self {
+        let (previous, next) = (calendar.clone(), calendar.clone());
+        let (back, forward) = match theme.locale().is_rtl() {
+            true => (Icons::Right, Icons::Left),
+            false => (Icons::Left, Icons::Right),
+        };
+        let month = calendar.lock().unwrap().month;
+        let title = Text::new(theme, &Self::title(theme, month), TextSize::H5, TextStyle::Heading, Align::Center, None);
+        let layout = Row::new(8.0, Offset::Center, Size::Fit, Padding::default());
+        MonthHeader(
+            Directional::new(layout, theme.locale()),
+            GhostIconButton::new(theme, back, move |_: &mut Context, _: &Theme| previous.lock().unwrap().step(-1)),
+            title,
+            GhostIconButton::new(theme, forward, move |_: &mut Context, _: &Theme| next.lock().unwrap().step(1)),
+        )
+    }
+
+    fn title(theme: &Theme, month: NaiveDate) -> String {
+        format!("{} {}", theme.tr(&format!("month-{}", month.month())), month.year())
+    }
+}
+
+#[derive(Debug, Component, Clone)]
+struct Weekdays(Directional, Vec<Text>);
+impl OnEvent for Weekdays {}
+
+impl Weekdays {
+    const KEYS: [&'static str; 7] = ["weekday-mon", "weekday-tue", "weekday-wed", "weekday-thu", "weekday-fri", "weekday-sat", "weekday-sun"];
+
+    fn new(theme: &Theme) -> Self {
+        let names = Self::KEYS.iter().map(|key| Text::new(theme, &theme.tr(key), TextSize::Sm, TextStyle::Secondary, Align::Center, None)).collect();
+        Weekdays(Directional::new(Week::layout(), theme.locale()), names)
+    }
+}
+
+#[derive(Debug, Component, Clone)]
+struct Week(Directional, Vec<DayCell>);
+impl OnEvent for Week {}
+
+impl Week {
+    fn layout() -> Row {Row::new(4.0, Offset::Center, Size::Fit, Padding::default())}
+
+    /// Six Monday-first weeks covering the shown month, with blanks outside of it.
+    fn month(theme: &Theme, calendar: &Calendar, state: &Arc<Mutex<Calendar>>, on_change: &OnDate) -> Vec<Week> {
+        let lead = calendar.month.weekday().num_days_from_monday() as u64;
+        let start = calendar.month.checked_sub_days(Days::new(lead)).unwrap_or(calendar.month);
+        let today = Local::now().date_naive();
+
+        (0..6).map(|week| {
+            let days = (0..7).map(|day| {
+                let date = start.checked_add_days(Days::new(week * 7 + day)).unwrap_or(start);
+                match date.month() == calendar.month.month() {
+                    true => DayCell::new(theme, date, calendar, date == today, state, on_change),
+                    false => DayCell::blank(theme),
+                }
+            }).collect();
+            Week(Directional::new(Self::layout(), theme.locale()), days)
+        }).collect()
+    }
+}
+
+#[derive(Debug, Component, Clone)]
+struct DayCell(Stack, interactions::Button);
+impl OnEvent for DayCell {}
+
+impl DayCell {
+    const SIZE: f32 = 40.0;
+
+    fn new(theme: &Theme, date: NaiveDate, calendar: &Calendar, is_today: bool, state: &Arc<Mutex<Calendar>>, on_change: &OnDate) -> Self {
+        let colors = theme.colors();
+        let allowed = calendar.allows(date);
+        let (background, outline, style) = match (calendar.selected == Some(date), allowed) {
+            (true, _) => (colors.get(ptsd::Brand), None, TextStyle::Heading),
+            (false, true) => (Color::TRANSPARENT, is_today.then(|| (1.0, colors.get(ptsd::Brand))), TextStyle::Primary),
+            (false, false) => (Color::TRANSPARENT, None, TextStyle::Secondary),
+        };
+        let content = DayContent::new(theme, &date.day().to_string(), style, background, outline);
+
+        let (theme, state, on_change) = (theme.clone(), state.clone(), on_change.clone());
+        let callback = Box::new(move |ctx: &mut Context| {
+            let mut calendar = state.lock().unwrap();
+            if !calendar.allows(date) { return; }
+            calendar.select(date);
+            drop(calendar);
+            on_change.call(ctx, &theme, date);
+        });
+        DayCell(Stack::default(), interactions::Button::new(content, None::<DayContent>, None::<DayContent>, None::<DayContent>, None::<DayContent>, callback, false))
+    }
+
+    fn blank(theme: &Theme) -> Self {
+        let content = DayContent::new(theme, "", TextStyle::Secondary, Color::TRANSPARENT, None);
+        DayCell(Stack::default(), interactions::Button::new(content, None::<DayContent>, None::<DayContent>, None::<DayContent>, None::<DayContent>, Box::new(|_: &mut Context| {}), false))
+    }
+}
+
+#[derive(Debug, Component, Clone)]
+struct DayContent(Stack, Rectangle, Text);
+impl OnEvent for DayContent {}
+
+impl DayContent {
+    fn new(theme: &Theme, label: &str, style: TextStyle, background: Color, outline: Option<(f32, Color)>) -> Self {
+        let layout = Stack(Offset::Center, Offset::Center, Size::Static(DayCell::SIZE), Size::Static(DayCell::SIZE), Padding::default());
+        let text = Text::new(theme, label, TextSize::Md, style, Align::Center, None);
+        DayContent(layout, Rectangle::new(background, DayCell::SIZE / 2.0, outline), text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {NaiveDate::from_ymd_opt(2026, 3, d).unwrap()}
+    fn next(date: NaiveDate) -> Option<NaiveDate> {date.checked_add_days(Days::new(1))}
+    fn previous(date: NaiveDate) -> Option<NaiveDate> {date.checked_sub_days(Days::new(1))}
+
+    fn calendar(min: Option<NaiveDate>, max: Option<NaiveDate>, disabled: impl Fn(NaiveDate) -> bool + Send + Sync + 'static) -> Calendar {
+        Calendar { month: day(1), selected: None, min, max, disabled: Some(Arc::new(disabled)) }
+    }
+
+    #[test]
+    fn seek_skips_disabled_days() {
+        let weekends = calendar(None, None, |date| date.weekday().number_from_monday() > 5);
+        // 2026-03-06 is a Friday.
+        assert_eq!(weekends.seek(day(6), next), Some(day(9)));
+        assert_eq!(weekends.seek(day(9), previous), Some(day(6)));
+    }
+
+    #[test]
+    fn seek_stops_at_the_bounds() {
+        let bounded = calendar(Some(day(5)), Some(day(10)), |date| date == day(9) || date == day(4));
+        assert_eq!(bounded.seek(day(8), next), Some(day(10)));
+        assert_eq!(bounded.seek(day(10), next), None);
+        assert_eq!(bounded.seek(day(5), previous), None);
+    }
+
+    #[test]
+    fn seek_gives_up_without_bounds() {
+        assert_eq!(calendar(None, None, |_| true).seek(day(1), next), None);
+    }
+}
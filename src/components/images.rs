@@ -1,8 +1,11 @@
-use prism::layout::SizeRequest;
-use prism::drawable::{Drawable, SizedTree, RequestTree, Rect}; 
+use prism::layout::{SizeRequest, Stack};
+use prism::drawable::{Drawable, Component, SizedTree, RequestTree, Rect}; 
 use prism::canvas::{ShapeType, Image, Area as CanvasArea, Item as CanvasItem};
+use prism::event::{OnEvent, Event};
+use prism::Context;
 
-use crate::theme::{Theme, Color, Icons};
+use crate::theme::{Theme, Color, Icons, ThemeChanged};
+use crate::components::Paint;
 
 // use std::io::BufWriter;
 use std::sync::Arc;
@@ -26,6 +29,24 @@ impl Icon {
     pub fn new(theme: &Theme, name: Icons, color: Option<Color>, size: f32) -> Image {
         Image{shape: ShapeType::Rectangle(0.0, (size, size), 0.0), image: theme.icons().get(name), color: color.map(|c| c.into())}
     }
+
+    /// An icon whose image and color follow [`ThemeChanged`].
+    pub fn themed(theme: &Theme, name: Icons, color: Paint, size: f32) -> ThemedIcon {
+        ThemedIcon(Stack::default(), Icon::new(theme, name, Some(color.get(theme)), size), name, color, size)
+    }
+}
+
+/// An [`Icon`] that is looked up again, with its [`Paint`], when the theme changes.
+#[derive(Debug, Component, Clone)]
+pub struct ThemedIcon(Stack, Image, #[skip] Icons, #[skip] Paint, #[skip] f32);
+
+impl OnEvent for ThemedIcon {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.1 = Icon::new(theme, self.2, Some(self.3.get(theme)), self.4);
+        }
+        vec![event]
+    }
 }
 
 /// ## Aspect Ratio Image
@@ -1,12 +1,13 @@
-use prism::event::{OnEvent, KeyboardState, KeyboardEvent, NamedKey, Key, Modifiers};
+use prism::event::{OnEvent, Event, KeyboardState, KeyboardEvent, NamedKey, Key, Modifiers};
 use prism::layout::{Stack, Column, Row, Offset};
-use prism::drawable::{Drawable, Component};
+use prism::drawable::{Drawable, Component, SizedTree};
 use prism::canvas::Align;
 use prism::{Context};
 
 use ptsd::interactions;
 
-use crate::theme::{self, Theme, Icons};
+use crate::Callback;
+use crate::theme::{self, Theme, Icons, ThemeChanged};
 use crate::components::Icon;
 use crate::components::text::{Text, TextStyle};
 use crate::components::button::{ButtonWidth, ButtonSize, Button};
@@ -21,7 +22,7 @@ impl Keypad {
             GhostButtonRow::new(theme, vec![KeypadButton::char('1'), KeypadButton::char('2'), KeypadButton::char('3')]),
             GhostButtonRow::new(theme, vec![KeypadButton::char('4'), KeypadButton::char('5'), KeypadButton::char('6')]),
             GhostButtonRow::new(theme, vec![KeypadButton::char('7'), KeypadButton::char('8'), KeypadButton::char('9')]),
-            GhostButtonRow::new(theme, vec![KeypadButton::char('.'), KeypadButton::char('0'), KeypadButton::delete()]),
+            GhostButtonRow::new(theme, vec![KeypadButton::char(theme.locale().number_format().decimal), KeypadButton::char('0'), KeypadButton::delete()]),
         ])
     }
 }
@@ -55,10 +56,22 @@ impl GhostButtonRow {
 }
 
 #[derive(Debug, Component, Clone)]
-struct GhostButton(Stack, pub interactions::Button);
-impl OnEvent for GhostButton {}
+struct GhostButton(Stack, pub interactions::Button, #[skip] Option<String>, #[skip] Option<Icons>, #[skip] Box<dyn Callback>);
+impl OnEvent for GhostButton {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            *self = Self::build(theme, self.2.clone().as_deref(), self.3, self.4.clone());
+        }
+        vec![event]
+    }
+}
+
 impl GhostButton {
-    fn new(theme: &Theme, label: Option<&str>, icon: Option<Icons>, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+    fn new(theme: &Theme, label: Option<&str>, icon: Option<Icons>, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+        Self::build(theme, label, icon, Box::new(on_click))
+    }
+
+    fn build(theme: &Theme, label: Option<&str>, icon: Option<Icons>, on_click: Box<dyn Callback>) -> Self {
         let colors = theme::Button::get(theme.colors(), theme::Variant::Ghost);
         let default =  {
             let font_size = ButtonSize::Large.font();
@@ -69,8 +82,8 @@ impl GhostButton {
             Button::new(drawables, ButtonSize::Large, ButtonWidth::Fill, Offset::Center, colors.default.background, colors.default.outline)
         };
         
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        GhostButton(Stack::default(), interactions::Button::new(default, None::<Button>, None::<Button>, None::<Button>, None::<Button>, callback, false))
+        let (theme, mut callback) = (theme.clone(), on_click.clone());
+        let callback = Box::new(move |ctx: &mut Context| (callback)(ctx, &theme));
+        GhostButton(Stack::default(), interactions::Button::new(default, None::<Button>, None::<Button>, None::<Button>, None::<Button>, callback, false), label.map(|l| l.to_string()), icon, on_click)
     }
 }
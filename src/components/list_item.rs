@@ -1,5 +1,5 @@
 use prism::event::{OnEvent, Event, TickEvent};
-use prism::canvas::{Align, Image};
+use prism::canvas::Align;
 use prism::layout::{Column, Stack, Row, Padding, Offset, Size};
 use prism::drawable::{Component, SizedTree};
 use prism::display::EitherOr;
@@ -10,7 +10,8 @@ use ptsd::utils::TitleSubtitle;
 
 use crate::theme::{Theme, Color, Icons};
 use crate::components::text::{Text, TextSize, ExpandableText, TextStyle};
-use crate::components::Icon;
+use crate::components::{Icon, ThemedIcon, Paint};
+use crate::locale::Directional;
 use crate::components::avatar::{Avatar, AvatarContent, AvatarSize};
 
 use std::sync::Arc;
@@ -70,7 +71,7 @@ impl ListItem {
 }
 
 #[derive(Debug, Component, Clone)]
-struct ListItemContent(Row, Option<Image>, Option<Avatar>, ListItemData, Option<Image>);
+struct ListItemContent(Directional, Option<ThemedIcon>, Option<Avatar>, ListItemData, Option<ThemedIcon>);
 impl OnEvent for ListItemContent {}
 
 impl ListItemContent {
@@ -83,23 +84,23 @@ impl ListItemContent {
         icon_l: Option<Icons>,
         icon_r: Option<Icons>,
     ) -> Self {
-        let c = theme.colors().get(ptsd::Text::Primary); 
+        let c = Paint::new(|theme| theme.colors().get(ptsd::Text::Primary));
         let avatar = avatar.map(|data| Avatar::new(theme, data, None, false, AvatarSize::Md, None));
         let content = ListItemData::new(theme, left, right);
-        let icon_l = icon_l.map(|i| Icon::new(theme, i, Some(c), 24.0));
-        let icon_r = icon_r.map(|i| Icon::new(theme, i, Some(c), 16.0));
+        let icon_l = icon_l.map(|i| Icon::themed(theme, i, c.clone(), 24.0));
+        let icon_r = icon_r.map(|i| Icon::themed(theme, i, c, 16.0));
         let layout = Row::new(16.0, Offset::Center, Size::Fit, Padding(0.0, 16.0, 0.0, 16.0));
-        ListItemContent(layout, icon_l, avatar, content, icon_r)
+        ListItemContent(Directional::new(layout, theme.locale()), icon_l, avatar, content, icon_r)
     }
 }
 
 #[derive(Debug, Component, Clone)]
-struct ListItemData(Row, LeftData, Option<RightData>);
+struct ListItemData(Directional, LeftData, Option<RightData>);
 impl OnEvent for ListItemData {}
 
 impl ListItemData {
     fn new(theme: &Theme, left: ListItemInfoLeft, right: Option<TitleSubtitle>) -> Self {
-        ListItemData(Row::start(8.0), LeftData::new(theme, left), right.map(|info| RightData::new(theme, info)))
+        ListItemData(Directional::new(Row::start(8.0), theme.locale()), LeftData::new(theme, left), right.map(|info| RightData::new(theme, info)))
     }
 }
 
@@ -110,22 +111,23 @@ impl OnEvent for LeftData {}
 impl LeftData {
     pub fn new(theme: &Theme, info: ListItemInfoLeft) -> Self {
         let layout = Column::new(4.0, Offset::Start, Size::Fill, Padding::default(), None);
-        let subtitle = info.title.subtitle.map(|s| ExpandableText::new(theme, &s, TextSize::Xs, TextStyle::Secondary, Align::Left, Some(2)));
-        let description = info.description.map(|text| ExpandableText::new(theme, &text, TextSize::Xs, TextStyle::Secondary, Align::Left, Some(2)));
+        let align = theme.locale().start();
+        let subtitle = info.title.subtitle.map(|s| ExpandableText::new(theme, &s, TextSize::Xs, TextStyle::Secondary, align, Some(2)));
+        let description = info.description.map(|text| ExpandableText::new(theme, &text, TextSize::Xs, TextStyle::Secondary, align, Some(2)));
         LeftData(layout, TitleRow::new(theme, &info.title.title, info.flair), subtitle, description)
     }
 }
 
 #[derive(Debug, Component, Clone)]
-struct TitleRow(Row, ExpandableText, Option<Image>);
+struct TitleRow(Directional, ExpandableText, Option<ThemedIcon>);
 impl OnEvent for TitleRow {}
 
 impl TitleRow {
     fn new(theme: &Theme, title: &str, flair: Option<(Icons, Color)>) -> Self {
         let layout = Row::new(4.0, Offset::Center, Size::Fit, Padding::default());
-        let text = ExpandableText::new(theme, title, TextSize::H5, TextStyle::Heading, Align::Left, Some(1));
-        let flair = flair.map(|(name, color)| Icon::new(theme, name, Some(color), 16.0));
-        TitleRow(layout, text, flair)
+        let text = ExpandableText::new(theme, title, TextSize::H5, TextStyle::Heading, theme.locale().start(), Some(1));
+        let flair = flair.map(|(name, color)| Icon::themed(theme, name, Paint::new(move |_| color), 16.0));
+        TitleRow(Directional::new(layout, theme.locale()), text, flair)
     }
 }
 
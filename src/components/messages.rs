@@ -1,7 +1,7 @@
-use prism::event::{OnEvent, Event, TickEvent};
-use prism::layout::{Stack, Column, Row, Offset, Size, Padding};
+use prism::event::{OnEvent, Event, TickEvent, MouseEvent, MouseState};
+use prism::layout::{Area, Stack, Column, Row, Offset, Size, Padding};
 use prism::drawable::{Component, SizedTree};
-use prism::canvas::Align;
+use prism::canvas::{Align, Image, ShapeType};
 use prism::display::Bin;
 use ptsd::colors;
 use ptsd::utils::Timestamp;
@@ -12,13 +12,15 @@ use chrono::{Local, Duration};
 use std::sync::Arc;
 use image::RgbaImage;
 
-use crate::theme::{Theme, Icons};
-use crate::components::Rectangle;
+use crate::locale::Directional;
+use crate::theme::{Theme, Color, Icons};
+use crate::components::{Rectangle, Paint, Icon, ExpandableImage};
 
 use air::names::Name;
 
 use crate::components::avatar::{AvatarSize, AvatarContent, AvatarIconStyle, Avatar};
 use crate::components::text::{Text, ExpandableText, TextSize, TextStyle};
+use crate::components::{Markdown, Surface};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Profile {
@@ -46,6 +48,93 @@ pub struct Message {
     pub message: String,
     pub timestamp: Timestamp,
     pub author: Profile,
+    pub attachments: Vec<Attachment>,
+    pub reactions: Vec<Reaction>,
+    /// The message this one answers, quoted above its bubble.
+    pub reply_to: Option<Box<Message>>,
+    pub delivery: Delivery,
+}
+
+impl Message {
+    pub fn new(message: &str, timestamp: Timestamp, author: Profile) -> Self {
+        Message { message: message.to_string(), timestamp, author, attachments: vec![], reactions: vec![], reply_to: None, delivery: Delivery::default() }
+    }
+
+    pub fn attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.attachments = attachments;
+        self
+    }
+
+    pub fn reactions(mut self, reactions: Vec<Reaction>) -> Self {
+        self.reactions = reactions;
+        self
+    }
+
+    pub fn reply_to(mut self, message: Message) -> Self {
+        self.reply_to = Some(Box::new(message));
+        self
+    }
+
+    pub fn delivery(mut self, delivery: Delivery) -> Self {
+        self.delivery = delivery;
+        self
+    }
+}
+
+/// An emoji and how many people reacted with it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reaction {
+    pub emoji: String,
+    pub count: usize,
+    /// Whether the current user is among them.
+    pub mine: bool,
+}
+
+impl Reaction {
+    pub fn new(emoji: &str, count: usize, mine: bool) -> Self {
+        Reaction { emoji: emoji.to_string(), count, mine }
+    }
+}
+
+/// How far a sent message has got, shown beside the group's timestamp.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Delivery {
+    Sending,
+    #[default]
+    Sent,
+    Delivered,
+    Read,
+    Failed,
+}
+
+/// Emitted when a reaction under a message is tapped, carrying its emoji.
+#[derive(Debug, Clone)]
+pub struct ReactionEvent(pub String);
+
+impl Event for ReactionEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: &[Area]) -> Vec<Option<Box<dyn Event>>> {
+        children.iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Something sent along with a [`Message`], shown above its text in the bubble.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Attachment {
+    Image(Arc<RgbaImage>),
+    /// A file's name and size in bytes.
+    File {name: String, size: u64},
+    /// A preview of a shared URL.
+    Link {url: String, title: String, thumbnail: Option<Arc<RgbaImage>>},
+}
+
+/// Emitted when an attachment in a message bubble is tapped, so the app can open it full-screen.
+#[derive(Debug, Clone)]
+pub struct AttachmentEvent(pub Attachment);
+
+impl Event for AttachmentEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: &[Area]) -> Vec<Option<Box<dyn Event>>> {
+        children.iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
 }
 
 #[derive(Debug, Clone, Component)]
@@ -68,7 +157,6 @@ impl MessageGroups {
             if !collection.is_empty() && (!close_enough || author != prev_auth) {
                 let a = collection[0].author.clone();
                 let t = collection.last().unwrap().timestamp.clone();
-                let msgs = collection.iter().map(|m| m.message.as_str()).collect::<Vec<_>>();
                 let direction = if a.is_me(ctx) { Direction::Sent } else { Direction::Received };
 
                 let room_type = match is_room {
@@ -78,7 +166,7 @@ impl MessageGroups {
                 };
 
                 // println!("ROOM TYPE {:?} because {:?}", room_type, members.len());
-                new.push(MessageGroup::new(theme, msgs, t, a, room_type));
+                new.push(MessageGroup::new(theme, &collection, t, a, room_type));
 
                 collection.clear();
             }
@@ -92,10 +180,9 @@ impl MessageGroups {
         if !collection.is_empty() {
             let a = collection[0].author.clone();
             let t = collection.last().unwrap().timestamp.clone();
-            let msgs = collection.iter().map(|m| m.message.as_str()).collect::<Vec<_>>();
             let direction = if a.is_me(ctx) { Direction::Sent } else { Direction::Received };
 
-            new.push(MessageGroup::new(theme, msgs, t, a, match is_room {
+            new.push(MessageGroup::new(theme, &collection, t, a, match is_room {
                 true => Room::Room,
                 false if is_group => Room::Group(direction),
                 false => Room::Direct(direction),
@@ -107,10 +194,10 @@ impl MessageGroups {
 }
 
 #[derive(Debug, Clone, Component)]
-pub struct MessageGroup(Row, Option<Bin<Stack, Avatar>>, _MessageGroup);
+pub struct MessageGroup(Directional, Option<Bin<Stack, Avatar>>, _MessageGroup);
 impl OnEvent for MessageGroup {}
 impl MessageGroup {
-    pub fn new(theme: &Theme, messages: Vec<&str>, timestamp: Timestamp, profile: Profile, room: Room) -> Self {
+    pub fn new(theme: &Theme, messages: &[Message], timestamp: Timestamp, profile: Profile, room: Room) -> Self {
         let avatar = Avatar::new(theme, profile.pfp.clone(), None, false, AvatarSize::Xs, None);
 
         let (layout, avatar) = match room {
@@ -124,28 +211,30 @@ impl MessageGroup {
             Room::Group(Direction::Sent) => (Row::default(), None),
         };
 
-        MessageGroup(layout, avatar, _MessageGroup::new(theme, messages, timestamp, profile, room))
+        MessageGroup(Directional::new(layout, theme.locale()), avatar, _MessageGroup::new(theme, messages, timestamp, profile, room))
     }
 }
 
 #[derive(Debug, Clone, Component)]
 pub enum _MessageGroup {
-    Group {layout: Column, msg: _TextMessages, info: MessageInfo},
-    Direct {layout: Column, msg: _TextMessages, info: MessageInfo},
-    Room {layout: Column, info: MessageInfo, msg: _TextMessages},
+    Group {layout: Directional<Column>, msg: _TextMessages, info: MessageInfo},
+    Direct {layout: Directional<Column>, msg: _TextMessages, info: MessageInfo},
+    Room {layout: Directional<Column>, info: MessageInfo, msg: _TextMessages},
 }
 
 impl OnEvent for _MessageGroup {}
 impl _MessageGroup {
-    pub fn new(theme: &Theme, messages: Vec<&str>, timestamp: Timestamp, profile: Profile, room: Room) -> Self {
-        let info = MessageInfo::new(theme, profile.username, timestamp, room);
+    pub fn new(theme: &Theme, messages: &[Message], timestamp: Timestamp, profile: Profile, room: Room) -> Self {
+        let delivery = messages.last().map(|m| m.delivery).filter(|_| matches!(room, Room::Group(Direction::Sent) | Room::Direct(Direction::Sent)));
+        let info = MessageInfo::new(theme, profile.username, timestamp, room, delivery);
         let msg = _TextMessages::new(theme, messages, room);
+        let layout = |column: Column| Directional::new(column, theme.locale());
         match room {
-            Room::Room => _MessageGroup::Room {layout: Column::start(8.0), msg, info},
-            Room::Direct(Direction::Sent) => _MessageGroup::Direct {layout: Column::end(8.0), msg, info},
-            Room::Direct(Direction::Received) => _MessageGroup::Direct {layout: Column::start(8.0), msg, info},
-            Room::Group(Direction::Sent) => _MessageGroup::Group {layout: Column::end(8.0), msg, info},
-            Room::Group(Direction::Received) => _MessageGroup::Group {layout: Column::start(8.0), msg, info},
+            Room::Room => _MessageGroup::Room {layout: layout(Column::start(8.0)), msg, info},
+            Room::Direct(Direction::Sent) => _MessageGroup::Direct {layout: layout(Column::end(8.0)), msg, info},
+            Room::Direct(Direction::Received) => _MessageGroup::Direct {layout: layout(Column::start(8.0)), msg, info},
+            Room::Group(Direction::Sent) => _MessageGroup::Group {layout: layout(Column::end(8.0)), msg, info},
+            Room::Group(Direction::Received) => _MessageGroup::Group {layout: layout(Column::start(8.0)), msg, info},
         }
     }
 
@@ -159,65 +248,288 @@ impl _MessageGroup {
 }
 
 #[derive(Debug, Clone, Component)]
-pub struct MessageInfo(Row, Option<Text>, Option<Text>, Text);
+pub struct MessageInfo(Directional, Option<Text>, Option<Text>, Text, Option<Image>);
 impl OnEvent for MessageInfo {}
 impl MessageInfo {
-    pub fn new(theme: &Theme, name: String, timestamp: Timestamp, room: Room) -> Self {
+    /// `delivery` adds a status tick, for messages the current user sent.
+    pub fn new(theme: &Theme, name: String, timestamp: Timestamp, room: Room, delivery: Option<Delivery>) -> Self {
         let name = match room {
             Room::Room => Some(Text::new(theme, &name, TextSize::H5, TextStyle::Heading, Align::Left, None)),
             Room::Group(Direction::Received) => Some(Text::new(theme, &name, TextSize::Sm, TextStyle::Secondary, Align::Left, None)),
-            Room::Group(Direction::Sent) => Some(Text::new(theme, "You", TextSize::Sm, TextStyle::Secondary, Align::Left, None)),
+            Room::Group(Direction::Sent) => Some(Text::new(theme, &theme.tr("you"), TextSize::Sm, TextStyle::Secondary, Align::Left, None)),
             Room::Direct(_) => None,
         };
 
         let timestamp = Text::new(theme, &timestamp.precise(), TextSize::Sm, TextStyle::Secondary, Align::Left, None);
         let divider = name.is_some().then_some(Text::new(theme, "·", TextSize::Sm, TextStyle::Secondary, Align::Left, None));
 
-        MessageInfo(Row::center(4.0), name, divider, timestamp)
+        let colors = theme.colors();
+        let status = delivery.map(|delivery| {
+            let (icon, color) = match delivery {
+                Delivery::Sending => (Icons::Clock, colors.get(ptsd::Text::Secondary)),
+                Delivery::Sent => (Icons::Tick, colors.get(ptsd::Text::Secondary)),
+                Delivery::Delivered => (Icons::DoubleTick, colors.get(ptsd::Text::Secondary)),
+                Delivery::Read => (Icons::DoubleTick, colors.get(ptsd::Brand)),
+                Delivery::Failed => (Icons::Error, colors.get(ptsd::Status::Danger)),
+            };
+            Icon::new(theme, icon, Some(color), 16.0)
+        });
+
+        MessageInfo(Directional::new(Row::center(4.0), theme.locale()), name, divider, timestamp, status)
     }
 }
 
 #[derive(Debug, Clone, Component)]
-pub struct _TextMessages(Column, Vec<_TextMessage>);
+pub struct _TextMessages(Directional<Column>, Vec<_TextMessage>);
 impl OnEvent for _TextMessages {}
 impl _TextMessages {
-    pub fn new(theme: &Theme, messages: Vec<&str>, room: Room) -> Self {
+    pub fn new(theme: &Theme, messages: &[Message], room: Room) -> Self {
         let layout = match room {
             Room::Group(Direction::Sent) | Room::Direct(Direction::Sent) => Column::end(8.0),
             Room::Group(Direction::Received) | Room::Direct(Direction::Received) => Column::start(8.0),
             Room::Room => Column::start(0.0),
         };
 
-        _TextMessages(layout, messages.iter().map(|msg| _TextMessage::new(theme, msg, room)).collect::<Vec<_>>())
+        _TextMessages(Directional::new(layout, theme.locale()), messages.iter().map(|msg| _TextMessage::new(theme, msg, room)).collect::<Vec<_>>())
     }
 }
 
+/// A message's bubble with the message it replies to quoted above and its reactions below.
 #[derive(Debug, Clone, Component)]
-pub struct _TextMessage(Stack, Option<Rectangle>, Bin<Stack, ExpandableText>);
-impl OnEvent for _TextMessage {
-    fn on_event(&mut self, ctx: &mut Context, sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+pub struct _TextMessage(Directional<Column>, Option<QuotedReply>, MessageBubble, Option<ReactionRow>);
+impl OnEvent for _TextMessage {}
+
+impl _TextMessage {
+    pub fn new(theme: &Theme, message: &Message, style: Room) -> Self {
+        let layout = match style {
+            Room::Group(Direction::Sent) | Room::Direct(Direction::Sent) => Column::end(4.0),
+            _ => Column::start(4.0),
+        };
+        let reply = message.reply_to.as_ref().map(|reply| QuotedReply::new(theme, reply));
+        let reactions = (!message.reactions.is_empty()).then(|| ReactionRow::new(theme, &message.reactions));
+        _TextMessage(Directional::new(layout, theme.locale()), reply, MessageBubble::new(theme, message, style), reactions)
+    }
+}
+
+#[derive(Debug, Clone, Component)]
+pub struct MessageBubble(Stack, Option<Rectangle>, Bin<Stack, MessageBody>);
+impl OnEvent for MessageBubble {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
         if event.downcast_ref::<TickEvent>().is_some() {
-            let w = self.2.inner().0.inner().size().0.min(250.0) + 4.0;
+            let w = self.2.inner().width().min(MAX_WIDTH) + 4.0;
             *self.2.get_layout() = Stack::new(Offset::Center, Offset::Center, Size::Static(w), Size::Fit, Padding(0.0, 8.0, 0.0, 8.0));
         }
         vec![event]
     }
 }
 
-impl _TextMessage {
-    pub fn new(theme: &Theme, message: &str, style: Room) -> Self {
-        let text = ExpandableText::new(theme, message, TextSize::Md, TextStyle::Primary, Align::Left, None);
-        let background = match style {
-            Room::Group(Direction::Sent) | Room::Direct(Direction::Sent) => Some(theme.colors().get(colors::Brand)),
-            Room::Group(Direction::Received) | Room::Direct(Direction::Received) => Some(theme.colors().get(colors::Background::Secondary)),
-            Room::Room => None,
-        };
-
+impl MessageBubble {
+    pub fn new(theme: &Theme, message: &Message, style: Room) -> Self {
         let width = Size::custom(move |h: Vec<(f32, f32)>| (h.last().unwrap().0, (h.last().unwrap().1 + 24.0).max(42.0)));
         let height = Size::custom(move |h: Vec<(f32, f32)>| (h.last().unwrap().0, h.last().unwrap().1));
         let layout = Stack::new(Offset::Center, Offset::Center, width, height, Padding::default());
-        _TextMessage(layout, background.map(|c| Rectangle::new(c, 18.0, None)), Bin(Stack::default(), text))
+        let surface = match style {
+            Room::Group(Direction::Sent) | Room::Direct(Direction::Sent) => Surface::Brand,
+            Room::Group(Direction::Received) | Room::Direct(Direction::Received) => Surface::Secondary,
+            Room::Room => Surface::Background,
+        };
+        let background = match surface {
+            Surface::Brand => Some(Paint::new(|theme| theme.colors().get(colors::Brand))),
+            Surface::Secondary => Some(Paint::new(|theme| theme.colors().get(colors::Background::Secondary))),
+            Surface::Background => None,
+        };
+        MessageBubble(layout, background.map(|paint| Rectangle::themed(theme, paint, 18.0, None)), Bin(Stack::default(), MessageBody::new(theme, message, surface)))
+    }
+}
+
+/// The author and opening of the message being replied to, in an outlined box.
+#[derive(Debug, Clone, Component)]
+pub struct QuotedReply(Stack, Rectangle, Bin<Stack, QuoteLabel>);
+impl OnEvent for QuotedReply {}
+
+impl QuotedReply {
+    pub fn new(theme: &Theme, message: &Message) -> Self {
+        let outline = theme.colors().get(ptsd::Outline::Secondary);
+        let width = Size::custom(|w: Vec<(f32, f32)>| (w[1].0, w[1].1.min(MAX_WIDTH)));
+        let layout = Stack(Offset::Start, Offset::Start, width, Size::Fit, Padding::default());
+        let label = Bin(Stack(Offset::Start, Offset::Start, Size::Fit, Size::Fit, Padding(12.0, 8.0, 12.0, 8.0)), QuoteLabel::new(theme, message));
+        QuotedReply(layout, Rectangle::new(Color::TRANSPARENT, 12.0, Some((1.0, outline))), label)
+    }
+}
+
+#[derive(Debug, Clone, Component)]
+struct QuoteLabel(Column, Text, ExpandableText);
+impl OnEvent for QuoteLabel {}
+
+impl QuoteLabel {
+    fn new(theme: &Theme, message: &Message) -> Self {
+        let align = theme.locale().start();
+        let preview = match (message.message.is_empty(), message.attachments.first()) {
+            (true, Some(Attachment::File {name, ..})) => name.to_string(),
+            (true, Some(Attachment::Link {title, ..})) => title.to_string(),
+            (true, Some(Attachment::Image(_))) => theme.tr("message-photo"),
+            _ => message.message.to_string(),
+        };
+        QuoteLabel(
+            Column::new(2.0, Offset::Start, Size::Fill, Padding::default(), None),
+            Text::new(theme, &message.author.username, TextSize::Sm, TextStyle::Heading, align, None),
+            ExpandableText::new(theme, &preview, TextSize::Sm, TextStyle::Secondary, align, Some(2)),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Component)]
+pub struct ReactionRow(Directional, Vec<ReactionChip>);
+impl OnEvent for ReactionRow {}
+
+impl ReactionRow {
+    pub fn new(theme: &Theme, reactions: &[Reaction]) -> Self {
+        let layout = Row::new(4.0, Offset::Center, Size::Fit, Padding(8.0, 0.0, 8.0, 0.0));
+        ReactionRow(Directional::new(layout, theme.locale()), reactions.iter().map(|r| ReactionChip::new(theme, r)).collect())
+    }
+}
+
+/// An emoji and its count; outlined when the current user reacted with it.
+#[derive(Debug, Clone, Component)]
+pub struct ReactionChip(Stack, Rectangle, Bin<Stack, Text>, #[skip] String);
+
+impl OnEvent for ReactionChip {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(MouseEvent{state: MouseState::Pressed, position: Some(_), ..}) = event.downcast_ref::<MouseEvent>() {
+            ctx.emit(ReactionEvent(self.3.clone()));
+        }
+        vec![event]
+    }
+}
+
+impl ReactionChip {
+    pub fn new(theme: &Theme, reaction: &Reaction) -> Self {
+        let colors = theme.colors();
+        let outline = reaction.mine.then(|| (1.0, colors.get(ptsd::Brand)));
+        let background = Rectangle::new(colors.get(ptsd::Background::Secondary), 12.0, outline);
+        let label = Text::new(theme, &format!("{} {}", reaction.emoji, reaction.count), TextSize::Sm, TextStyle::Primary, Align::Center, None);
+        let layout = Stack(Offset::Center, Offset::Center, Size::Fit, Size::Fit, Padding::default());
+        ReactionChip(layout, background, Bin(Stack(Offset::Center, Offset::Center, Size::Fit, Size::Fit, Padding(8.0, 4.0, 8.0, 4.0)), label), reaction.emoji.to_string())
+    }
+}
+
+/// The widest a bubble's content gets.
+const MAX_WIDTH: f32 = 250.0;
+
+/// A message's attachments followed by its text.
+#[derive(Debug, Clone, Component)]
+pub struct MessageBody(Column, Vec<AttachmentView>, Option<Markdown>);
+impl OnEvent for MessageBody {}
+
+impl MessageBody {
+    /// `surface` is what the bubble is filled with.
+    pub fn new(theme: &Theme, message: &Message, surface: Surface) -> Self {
+        let attachments = message.attachments.iter().map(|a| AttachmentView::new(theme, a, surface)).collect();
+        let text = (!message.message.is_empty()).then(|| Markdown::new(theme, &message.message, TextSize::Md, TextStyle::Primary, surface));
+        MessageBody(Column::new(8.0, Offset::Start, Size::Fill, Padding::default(), None), attachments, text)
+    }
+
+    /// Attachments take the full bubble width; text alone only what it needs.
+    pub fn width(&self) -> f32 {
+        match self.1.is_empty() {
+            true => self.2.as_ref().map(|text| text.width()).unwrap_or_default(),
+            false => MAX_WIDTH,
+        }
+    }
+}
+
+/// An image, file or link preview; tapping it emits an [`AttachmentEvent`].
+#[derive(Debug, Clone, Component)]
+pub struct AttachmentView(Stack, Option<Rectangle>, Option<ExpandableImage>, Option<FileCard>, Option<LinkCard>, #[skip] Attachment);
+
+impl OnEvent for AttachmentView {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(MouseEvent{state: MouseState::Pressed, position: Some(_), ..}) = event.downcast_ref::<MouseEvent>() {
+            ctx.emit(AttachmentEvent(self.5.clone()));
+        }
+        vec![event]
+    }
+}
+
+impl AttachmentView {
+    pub fn new(theme: &Theme, attachment: &Attachment, surface: Surface) -> Self {
+        let layout = Stack(Offset::Start, Offset::Start, Size::Fill, Size::Fit, Padding::default());
+        let card = Some(Rectangle::themed(theme, Paint::new(move |theme| surface.inset(theme)), 10.0, None));
+        let (background, image, file, link) = match attachment {
+            Attachment::Image(image) => (None, Some(rounded(image, MAX_WIDTH, 10.0)), None, None),
+            Attachment::File {name, size} => (card, None, Some(FileCard::new(theme, name, *size)), None),
+            Attachment::Link {url, title, thumbnail} => (card, None, None, Some(LinkCard::new(theme, url, title, thumbnail.as_ref()))),
+        };
+        AttachmentView(layout, background, image, file, link, attachment.clone())
+    }
+}
+
+/// A file icon beside the file's name and size.
+#[derive(Debug, Clone, Component)]
+pub struct FileCard(Directional, Image, FileLabel);
+impl OnEvent for FileCard {}
+
+impl FileCard {
+    pub fn new(theme: &Theme, name: &str, size: u64) -> Self {
+        let color = theme.colors().get(ptsd::Text::Primary);
+        let layout = Row::new(8.0, Offset::Center, Size::Fit, Padding(8.0, 8.0, 8.0, 8.0));
+        FileCard(Directional::new(layout, theme.locale()), Icon::new(theme, Icons::File, Some(color), 32.0), FileLabel::new(theme, name, size))
+    }
+}
+
+#[derive(Debug, Clone, Component)]
+struct FileLabel(Column, ExpandableText, Text);
+impl OnEvent for FileLabel {}
+
+impl FileLabel {
+    fn new(theme: &Theme, name: &str, size: u64) -> Self {
+        let align = theme.locale().start();
+        FileLabel(
+            Column::new(2.0, Offset::Start, Size::Fill, Padding::default(), None),
+            ExpandableText::new(theme, name, TextSize::Md, TextStyle::Heading, align, Some(1)),
+            Text::new(theme, &file_size(theme, size), TextSize::Sm, TextStyle::Secondary, align, None),
+        )
+    }
+}
+
+/// The page's thumbnail, title and host.
+#[derive(Debug, Clone, Component)]
+pub struct LinkCard(Column, Option<ExpandableImage>, ExpandableText, Text);
+impl OnEvent for LinkCard {}
+
+impl LinkCard {
+    pub fn new(theme: &Theme, url: &str, title: &str, thumbnail: Option<&Arc<RgbaImage>>) -> Self {
+        let align = theme.locale().start();
+        let host = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let host = host.split(['/', '?', '#']).next().unwrap_or(host).trim_start_matches("www.");
+        LinkCard(
+            Column::new(4.0, Offset::Start, Size::Fill, Padding(8.0, 8.0, 8.0, 8.0), None),
+            thumbnail.map(|image| rounded(image, MAX_WIDTH - 16.0, 6.0)),
+            ExpandableText::new(theme, title, TextSize::Md, TextStyle::Heading, align, Some(2)),
+            Text::new(theme, host, TextSize::Sm, TextStyle::Secondary, align, None),
+        )
+    }
+}
+
+/// `image` scaled to `width` with rounded corners.
+fn rounded(image: &Arc<RgbaImage>, width: f32, radius: f32) -> ExpandableImage {
+    let size = (width, width * image.height() as f32 / image.width().max(1) as f32);
+    let mut image = ExpandableImage::new(image.clone(), Some(size));
+    image.image().shape = ShapeType::RoundedRectangle(0.0, size, 0.0, radius);
+    image
+}
+
+/// A byte count in the largest fitting unit, e.g. "1.5 MB".
+fn file_size(theme: &Theme, bytes: u64) -> String {
+    let units = ["file-size-b", "file-size-kb", "file-size-mb", "file-size-gb"];
+    let (mut size, mut unit) = (bytes as f64, 0);
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
     }
+    let mut format = theme.locale().number_format().clone();
+    format.decimals = if unit == 0 { 0 } else { 1 };
+    theme.tr_args(units[unit], &[("size", &format.number(size))])
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
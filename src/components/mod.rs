@@ -3,9 +3,15 @@ pub mod avatar;
 pub mod list_item;
 pub mod text;
 
+mod rich_text;
+pub use rich_text::{RichText, RichTextEvent, Run, RunStyle, Markdown, MarkdownBlock, Surface};
+
 mod text_input;
 pub use text_input::{TextInput, TextInputEvent};
 
+mod text_area;
+pub use text_area::TextArea;
+
 mod radio;
 pub use radio::RadioSelector;
 
@@ -33,6 +39,12 @@ pub use data_item::DataItem;
 mod numerical_input;
 pub use numerical_input::*;
 
+mod date_picker;
+pub use date_picker::DatePicker;
+
+mod time_picker;
+pub use time_picker::TimePicker;
+
 mod keypad;
 pub use keypad::Keypad;
 
@@ -1,12 +1,27 @@
 use prism::{event, event::{Event, OnEvent, Key, NamedKey, TickEvent}, display::{Opt, EitherOr}, Context, canvas::{Align, Image}, layout::{Stack, Row, Padding, Size, Offset, Column}, drawable::{Component, SizedTree}, emitters};
 use crate::components::text::{Text, ExpandableText, TextStyle};
 use crate::components::Icon;
-use crate::theme::{Theme, Icons};
+use crate::theme::{Theme, Icons, ThemeChanged};
+use crate::locale::{Locale, NumberFormat, CurrencyPosition};
 use ptsd::{colors, TextSize};
 
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
 #[derive(Debug, Clone, Component)]
-pub struct NumericalInput(Stack, _NumericalInput);
-impl OnEvent for NumericalInput {}
+pub struct NumericalInput(Stack, _NumericalInput, #[skip] Option<Units>, #[skip] Option<(Constraints, Locale)>);
+impl OnEvent for NumericalInput {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if event.downcast_ref::<TickEvent>().is_some() && let Some((constraints, locale)) = &self.3 {
+            let value = self.value_decimal().filter(|value| value.mantissa != 0);
+            let result = value.map_or(Ok(()), |value| constraints.check(&value, locale));
+            self.1.invalid = result.err();
+        }
+        vec![event]
+    }
+}
+
 impl NumericalInput {
     pub fn numerical(theme: &Theme, instructions: &str) -> Self {
         NumericalInput::new(theme, instructions, SlotDisplay::numerical(theme))
@@ -23,33 +38,117 @@ impl NumericalInput {
     pub fn display(theme: &Theme, amount: f32, instructions: &str) -> Self {
         let input = _NumericalInput::new(theme, instructions, SlotDisplay::display(theme, amount));
         let layout = Stack(Offset::Center, Offset::Center, Size::Fill, Size::Fit, Padding::default());
-        NumericalInput(layout, input)
+        NumericalInput(layout, input, None, None)
+    }
+
+    /// Limits what can be typed and checks the value on every keystroke,
+    /// showing the first failed constraint as the input's error. An empty (zero)
+    /// amount is not checked, so the error only appears once something is typed.
+    ///
+    /// ```rust
+    /// let input = NumericalInput::numerical(theme, "Enter amount").with_constraints(theme,
+    ///     Constraints::new().min(1.0).max(500.0).decimals(2).validator(|v| match v.mantissa % 5 == 0 {
+    ///         true => Ok(()),
+    ///         false => Err("Amount must be a multiple of 5 cents".to_string()),
+    ///     })
+    /// );
+    /// ```
+    pub fn with_constraints(mut self, theme: &Theme, constraints: Constraints) -> Self {
+        self.1.inner.1.limit(constraints.max_digits, constraints.decimals);
+        self.3 = Some((constraints, theme.locale().clone()));
+        self
+    }
+
+    /// A bitcoin amount entered in `base` units. With an exchange `rate` (fiat per
+    /// bitcoin) the display can be switched to fiat and back with [`NumericalInput::toggle`].
+    pub fn bitcoin(theme: &Theme, instructions: &str, base: Denomination, rate: Option<f64>) -> Self {
+        let mut input = NumericalInput::new(theme, instructions, SlotDisplay::denominated(theme, base, ""));
+        input.2 = Some(Units { display: base, base, rate, fiat: theme.locale().number_format().clone() });
+        input
+    }
+
+    pub fn denomination(&self) -> Option<Denomination> {self.2.as_ref().map(|units| units.display)}
+
+    pub fn set_rate(&mut self, rate: f64) {
+        if let Some(units) = &mut self.2 { units.rate = Some(rate); }
+    }
+
+    /// Switches the display between fiat and bitcoin, keeping the entered amount.
+    pub fn toggle(&mut self, theme: &Theme) {
+        let Some(units) = &self.2 else { return };
+        let next = match units.display {
+            Denomination::Fiat if units.base == Denomination::Fiat => Denomination::Bitcoin,
+            Denomination::Fiat => units.base,
+            _ => Denomination::Fiat,
+        };
+        self.set_denomination(theme, next);
+    }
+
+    /// Redisplays the entered amount in `denomination`. Does nothing if converting
+    /// to or from fiat without an exchange rate.
+    pub fn set_denomination(&mut self, theme: &Theme, denomination: Denomination) {
+        let Some(mut units) = self.2.clone() else { return };
+        units.fiat = theme.locale().number_format().clone();
+        self.2 = Some(units.clone());
+        let Some(amount) = self.convert(units.display, denomination) else { return };
+        let amount = match amount.contains('.') {
+            true => amount.trim_end_matches('0').trim_end_matches('.').to_string(),
+            false => amount,
+        };
+        self.1.inner.1 = SlotDisplay::denominated(theme, denomination, &amount);
+        if let Some((constraints, _)) = &self.3 { self.1.inner.1.limit(constraints.max_digits, constraints.decimals); }
+        self.2 = Some(Units { display: denomination, ..units });
     }
 
+    /// The entered value in a locale-independent form: `1234.56` for amounts,
+    /// `MM-DD` for dates and `HH:MM` for times. Bitcoin amounts are returned in the
+    /// base [`Denomination`], whichever one is displayed.
     pub fn value(&self) -> String {
-        let mut out = String::new();
+        match &self.2 {
+            Some(units) if units.display != units.base => self.convert(units.display, units.base).unwrap_or_else(|| self.displayed()),
+            _ => self.displayed(),
+        }
+    }
 
-        for slot in &self.1.inner.1.1 {
-            let s = match slot.2.get_visual() {
-                SlotVisual::Primary(s) | SlotVisual::Ghost(s) => s,
-                SlotVisual::None => continue,
-            };
+    /// The value as a number, or `None` for dates and times.
+    pub fn value_f64(&self) -> Option<f64> {self.value().parse().ok()}
 
-            for ch in s.chars() {
-                match ch.is_ascii_digit() || matches!(ch, '$' | '.' | ',' | '-' | ':' | '/') {
-                    true => out.push(ch),
-                    false => out.push('0'),
-                }
+    /// The value as an exact decimal, or `None` for dates and times.
+    pub fn value_decimal(&self) -> Option<Decimal> {self.value().parse().ok()}
+
+    fn convert(&self, from: Denomination, to: Denomination) -> Option<String> {
+        let displayed = self.displayed();
+        if from == to { return Some(displayed); }
+        let units = self.2.as_ref()?;
+        let rate = units.rate.and_then(|rate| rate.to_string().parse::<Decimal>().ok());
+        let bitcoin = from.to_bitcoin(displayed.parse().ok()?, rate)?;
+        let decimals = to.format(&units.fiat).decimals as u32;
+        Some(to.of_bitcoin(bitcoin, rate)?.round(decimals)?.to_string())
+    }
+
+    fn displayed(&self) -> String {
+        let display = &self.1.inner.1;
+        let mut out = String::new();
+
+        for slot in &display.1 {
+            match slot.2.get_visual() {
+                SlotVisual::Primary(s) => out.push_str(&s),
+                SlotVisual::Ghost(s) => s.chars().for_each(|ch| out.push(if ch.is_ascii_digit() { ch } else { '0' })),
+                SlotVisual::None => {},
             }
         }
 
-        out
+        match &display.3 {
+            SlotFormat::Numerical(format) => format.normalize(&out),
+            SlotFormat::Date(format) => format.normalize_date(&out),
+            SlotFormat::Time => out,
+        }
     }
 
     fn new(theme: &Theme, instructions: &str, input: SlotDisplay) -> Self {
         let input = _NumericalInput::new(theme, instructions, input);
         let layout = Stack(Offset::Center, Offset::Center, Size::Fill, Size::Fill, Padding::default());
-        NumericalInput(layout, input)
+        NumericalInput(layout, input, None, None)
     }
 
     pub fn error(&mut self, error: Result<String, String>) {
@@ -57,19 +156,205 @@ impl NumericalInput {
     }
 }
 
+/// The unit a bitcoin [`NumericalInput`] is shown or reported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Denomination {
+    Fiat,
+    Bitcoin,
+    Sats,
+}
+
+impl Denomination {
+    const SATS_PER_BITCOIN: Decimal = Decimal { mantissa: 100_000_000, scale: 0 };
+    const BITCOIN_PER_SAT: Decimal = Decimal { mantissa: 1, scale: 8 };
+    /// Decimal places kept when dividing by an exchange rate, before rounding to the target unit.
+    const PRECISION: u32 = 18;
+
+    /// How amounts in this unit are written, taking separators from the locale's fiat format.
+    pub fn format(&self, fiat: &NumberFormat) -> NumberFormat {
+        match self {
+            Denomination::Fiat => fiat.clone(),
+            Denomination::Bitcoin => NumberFormat { currency: "₿".to_string(), position: CurrencyPosition::Prefix, spaced: false, decimals: 8, ..fiat.clone() },
+            Denomination::Sats => NumberFormat { currency: "sats".to_string(), position: CurrencyPosition::Suffix, spaced: true, decimals: 0, ..fiat.clone() },
+        }
+    }
+
+    fn digits(&self) -> usize {
+        match self {
+            Denomination::Fiat => 6,
+            Denomination::Bitcoin => 5,
+            Denomination::Sats => 12,
+        }
+    }
+
+    fn to_bitcoin(self, amount: Decimal, rate: Option<Decimal>) -> Option<Decimal> {
+        match self {
+            Denomination::Fiat => rate.filter(|r| r.mantissa > 0).and_then(|r| amount.div(r, Self::PRECISION)),
+            Denomination::Bitcoin => Some(amount),
+            Denomination::Sats => amount.mul(Self::BITCOIN_PER_SAT),
+        }
+    }
+
+    fn of_bitcoin(self, bitcoin: Decimal, rate: Option<Decimal>) -> Option<Decimal> {
+        match self {
+            Denomination::Fiat => rate.and_then(|r| bitcoin.mul(r)),
+            Denomination::Bitcoin => Some(bitcoin),
+            Denomination::Sats => bitcoin.mul(Self::SATS_PER_BITCOIN),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Units {
+    display: Denomination,
+    base: Denomination,
+    rate: Option<f64>,
+    /// The locale's fiat format, which sets how many decimals fiat amounts keep.
+    fiat: NumberFormat,
+}
+
+/// An exact decimal number: `mantissa × 10^-scale`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Decimal {
+    pub mantissa: i128,
+    pub scale: u32,
+}
+
+impl Decimal {
+    pub fn to_f64(self) -> f64 {self.mantissa as f64 / 10f64.powi(self.scale as i32)}
+
+    /// This number with exactly `scale` decimals, rounding half away from zero.
+    /// `None` if it no longer fits.
+    pub fn round(self, scale: u32) -> Option<Decimal> {
+        let mantissa = match scale >= self.scale {
+            true => self.mantissa.checked_mul(10i128.checked_pow(scale - self.scale)?)?,
+            false => rounded_div(self.mantissa, 10i128.checked_pow(self.scale - scale)?)?,
+        };
+        Some(Decimal { mantissa, scale })
+    }
+
+    fn mul(self, other: Decimal) -> Option<Decimal> {
+        Some(Decimal { mantissa: self.mantissa.checked_mul(other.mantissa)?, scale: self.scale + other.scale })
+    }
+
+    /// `self / other`, rounded to `scale` decimals.
+    fn div(self, other: Decimal, scale: u32) -> Option<Decimal> {
+        let exponent = scale as i64 + other.scale as i64 - self.scale as i64;
+        let (numerator, denominator) = match exponent >= 0 {
+            true => (self.mantissa.checked_mul(10i128.checked_pow(exponent as u32)?)?, other.mantissa),
+            false => (self.mantissa, other.mantissa.checked_mul(10i128.checked_pow(exponent.unsigned_abs() as u32)?)?),
+        };
+        Some(Decimal { mantissa: rounded_div(numerator, denominator)?, scale })
+    }
+}
+
+/// Integer division rounding half away from zero.
+fn rounded_div(numerator: i128, denominator: i128) -> Option<i128> {
+    let (quotient, remainder) = (numerator.checked_div(denominator)?, numerator % denominator);
+    let away = remainder.unsigned_abs() * 2 >= denominator.unsigned_abs();
+    Some(match away {
+        true => quotient + numerator.signum() * denominator.signum(),
+        false => quotient,
+    })
+}
+
+impl FromStr for Decimal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s),
+        };
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let digits = format!("{whole}{fraction}");
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) { return Err(format!("'{s}' is not a decimal number")); }
+        let mantissa = digits.parse::<i128>().map_err(|e| e.to_string())?;
+        Ok(Decimal { mantissa: if negative { -mantissa } else { mantissa }, scale: fraction.len() as u32 })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = format!("{:0>width$}", self.mantissa.unsigned_abs(), width = self.scale as usize + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - self.scale as usize);
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        match fraction.is_empty() {
+            true => write!(f, "{sign}{whole}"),
+            false => write!(f, "{sign}{whole}.{fraction}"),
+        }
+    }
+}
+
+/// Declarative limits for a [`NumericalInput`]. `max_digits` and `decimals` stop
+/// keystrokes outright; `min`, `max` and the validator report an error instead.
+#[derive(Clone, Default)]
+pub struct Constraints {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub max_digits: Option<usize>,
+    pub decimals: Option<usize>,
+    validator: Option<Arc<dyn Fn(&Decimal) -> Result<(), String> + Send + Sync>>,
+}
+
+impl Constraints {
+    pub fn new() -> Self {Constraints::default()}
+
+    pub fn min(mut self, min: f64) -> Self {self.min = Some(min); self}
+    pub fn max(mut self, max: f64) -> Self {self.max = Some(max); self}
+    pub fn max_digits(mut self, digits: usize) -> Self {self.max_digits = Some(digits); self}
+    pub fn decimals(mut self, decimals: usize) -> Self {self.decimals = Some(decimals); self}
+
+    pub fn validator(mut self, validator: impl Fn(&Decimal) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        self.validator = Some(Arc::new(validator));
+        self
+    }
+
+    /// Checks `value` against `min`, `max` and the validator, in that order.
+    pub fn check(&self, value: &Decimal, locale: &Locale) -> Result<(), String> {
+        let amount = value.to_f64();
+        if let Some(min) = self.min && amount < min {
+            return Err(locale.translate("amount-minimum", &[("min", &Self::written(locale, min))]));
+        }
+        if let Some(max) = self.max && amount > max {
+            return Err(locale.translate("amount-maximum", &[("max", &Self::written(locale, max))]));
+        }
+        self.validator.as_ref().map_or(Ok(()), |validator| (validator)(value))
+    }
+
+    /// `amount` in the locale's number format, with as many decimals as it needs.
+    fn written(locale: &Locale, amount: f64) -> String {
+        let decimals = amount.to_string().split_once('.').map_or(0, |(_, fraction)| fraction.len());
+        NumberFormat { decimals, ..locale.number_format().clone() }.number(amount)
+    }
+}
+
+impl fmt::Debug for Constraints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Constraints")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("max_digits", &self.max_digits)
+            .field("decimals", &self.decimals)
+            .field("validator", &self.validator.is_some())
+            .finish()
+    }
+}
+
 #[derive(Clone, Debug, Component)]
 pub struct _NumericalInput {
     layout: Column,
     inner: emitters::TextInput<SlotDisplay>, 
     _subtext: EitherOr<ExpandableText, NumericalInputError>, 
     #[skip] pub error: Option<String>,
+    #[skip] pub invalid: Option<String>,
     #[skip] pub subtext: (Option<String>, String),
 }
 
 impl OnEvent for _NumericalInput {
     fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
         if event.downcast_ref::<TickEvent>().is_some() {
-            if let Some(e) = &self.error {
+            if let Some(e) = self.invalid.as_ref().or(self.error.as_ref()) {
                 if !e.is_empty() {
                     self._subtext.right().2.spans[0] = e.to_string();
                     self._subtext.display_left(false); 
@@ -96,7 +381,7 @@ impl _NumericalInput {
         let layout = Column::new(24.0, Offset::Center, Size::Fit, Padding::new(64.0), None);
         let help = ExpandableText::new(theme, instructions, TextSize::Lg, TextStyle::Secondary, Align::Center, None);
         let error = NumericalInputError::new(theme);
-        _NumericalInput{ layout, inner: emitters::TextInput::new(input, false).1, _subtext: EitherOr::new(help, error), error: None, subtext: (None, instructions.to_string()) }
+        _NumericalInput{ layout, inner: emitters::TextInput::new(input, false).1, _subtext: EitherOr::new(help, error), error: None, invalid: None, subtext: (None, instructions.to_string()) }
     }
 
     pub fn error(&mut self, error: Result<String, String>) {
@@ -109,7 +394,14 @@ impl _NumericalInput {
 
 #[derive(Clone, Debug, Component)]
 pub struct NumericalInputError(Row, Image, Text);
-impl OnEvent for NumericalInputError {}
+impl OnEvent for NumericalInputError {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.1 = Icon::new(theme, Icons::Error, Some(theme.colors().get(ptsd::Status::Danger)), 18.0);
+        }
+        vec![event]
+    }
+}
 impl NumericalInputError {
     pub fn new(theme: &Theme) -> Self {
         let error = theme.colors().get(ptsd::Status::Danger);
@@ -119,38 +411,64 @@ impl NumericalInputError {
     }
 }
 
+/// The locale conventions a [`SlotDisplay`] was built with, used to normalize its value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlotFormat {
+    Numerical(NumberFormat),
+    Date(NumberFormat),
+    Time,
+}
+
 #[derive(Clone, Debug, Component)]
-pub struct SlotDisplay(Row, Vec<Slot>, #[skip] bool);
+pub struct SlotDisplay(Row, Vec<Slot>, #[skip] bool, #[skip] SlotFormat);
 impl SlotDisplay {
     pub fn numerical(theme: &Theme) -> Self {
-        let slots = vec![
-            Slot::new(theme, SlotType::Fixed('$')),
-            Slot::new(theme, SlotType::InputWithDefault(String::new(), 6, '0', InputFormat::Numerical)), 
-            Slot::new(theme, SlotType::TriggersGhost('.', false)), 
-            Slot::new(theme, SlotType::TriggeredGhostInputWithDefault(String::new(), 1, '0', false)),
-            Slot::new(theme, SlotType::TriggeredGhostInputWithDefault(String::new(), 1, '0', false)),
-        ];
+        SlotDisplay::amount(theme, theme.locale().number_format().clone(), 6, "")
+    }
 
-        SlotDisplay(Row::center(0.0), slots, true)
+    /// An editable amount in `denomination`, pre-filled with a normalized `value` such as `"0.0015"`.
+    pub fn denominated(theme: &Theme, denomination: Denomination, value: &str) -> Self {
+        let format = denomination.format(theme.locale().number_format());
+        SlotDisplay::amount(theme, format, denomination.digits(), value)
+    }
+
+    fn amount(theme: &Theme, format: NumberFormat, digits: usize, value: &str) -> Self {
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        let mut slots = Self::symbol(theme, &format, CurrencyPosition::Prefix);
+        slots.push(Slot::new(theme, SlotType::InputWithDefault(whole.trim_start_matches('0').to_string(), digits, '0', InputFormat::Numerical(format.grouping))));
+
+        if format.decimals > 0 {
+            let typed = value.contains('.');
+            let mut fraction = fraction.chars();
+            slots.push(Slot::new(theme, SlotType::TriggersGhost(format.decimal, typed)));
+            (0..format.decimals).for_each(|_| {
+                let digit = fraction.next().map(String::from).unwrap_or_default();
+                slots.push(Slot::new(theme, SlotType::TriggeredGhostInputWithDefault(digit, 1, '0', typed)));
+            });
+        }
+
+        slots.extend(Self::symbol(theme, &format, CurrencyPosition::Suffix));
+        SlotDisplay(Row::center(0.0), slots, true, SlotFormat::Numerical(format))
     }
 
     pub fn display(theme: &Theme, amount: f32) -> Self {
-        let chars = format!("{:.2}", amount).chars().collect::<Vec<char>>();
-        let mut slots = vec![Slot::new(theme, SlotType::Fixed('$'))];
-        chars.into_iter().for_each(|c| slots.push(Slot::new(theme, SlotType::Fixed(c))));
-        SlotDisplay(Row::center(0.0), slots, false)
+        let format = theme.locale().number_format().clone();
+        let slots = format.currency(amount as f64).chars().map(|c| Slot::new(theme, SlotType::Fixed(c))).collect();
+        SlotDisplay(Row::center(0.0), slots, false, SlotFormat::Numerical(format))
     }
 
     pub fn date(theme: &Theme) -> Self {
+        let format = theme.locale().number_format().clone();
+        let [a, b, c, d] = format.date_fields();
         let slots = vec![
-            Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, 'D')),
-            Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, 'D')),
-            Slot::new(theme, SlotType::Fixed('-')),
-            Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, 'M')),
-            Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, 'M')),
+            Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, a)),
+            Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, b)),
+            Slot::new(theme, SlotType::Fixed(format.date_separator)),
+            Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, c)),
+            Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, d)),
         ];
 
-        SlotDisplay(Row::center(0.0), slots, true)
+        SlotDisplay(Row::center(0.0), slots, true, SlotFormat::Date(format))
     }
 
     pub fn time(theme: &Theme) -> Self {
@@ -162,7 +480,36 @@ impl SlotDisplay {
             Slot::new(theme, SlotType::GhostInputWithDefault(String::new(), 1, '0')),
         ];
 
-        SlotDisplay(Row::center(0.0), slots, true)
+        SlotDisplay(Row::center(0.0), slots, true, SlotFormat::Time)
+    }
+
+    /// Caps the whole-number digits and removes decimal slots beyond `decimals`.
+    pub fn limit(&mut self, digits: Option<usize>, decimals: Option<usize>) {
+        if let Some(digits) = digits {
+            self.1.iter_mut().for_each(|slot| if let SlotType::InputWithDefault(_, limit, _, _) = &mut slot.2 { *limit = digits; });
+        }
+
+        if let Some(decimals) = decimals {
+            let mut kept = 0;
+            self.1.retain(|slot| match slot.2 {
+                SlotType::TriggersGhost(..) => decimals > 0,
+                SlotType::TriggeredGhostInputWithDefault(..) => { kept += 1; kept <= decimals },
+                _ => true,
+            });
+        }
+    }
+
+    /// Fixed slots for the currency symbol and its spacing, if it sits at `position`.
+    fn symbol(theme: &Theme, format: &NumberFormat, position: CurrencyPosition) -> Vec<Slot> {
+        if format.position != position { return vec![]; }
+        let mut text = format.currency.clone();
+        if format.spaced {
+            match position {
+                CurrencyPosition::Prefix => text.push(' '),
+                CurrencyPosition::Suffix => text.insert(0, ' '),
+            }
+        }
+        text.chars().map(|c| Slot::new(theme, SlotType::Fixed(c))).collect()
     }
 }
 
@@ -228,7 +575,7 @@ impl OnEvent for SlotDisplay {
                                 },
                                 _ => {} // later...
                             }
-                        } else if let SlotType::TriggersGhost(trigger, is_on) = &mut slot.2 && *trigger == character {
+                        } else if let SlotType::TriggersGhost(trigger, is_on) = &mut slot.2 && (*trigger == character || character == '.') {
                             *is_on = true;
                             edited = true;
                         }
@@ -288,7 +635,14 @@ impl OnEvent for SlotDisplay {
 
 #[derive(Clone, Debug, Component)]
 pub struct Slot(Stack, Opt<EitherOr<Text, Text>>, #[skip] SlotType); // text<primary, ghost>
-impl OnEvent for Slot {}
+impl OnEvent for Slot {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.1.inner().right().style = TextStyle::Label(theme.colors().get(colors::Text::Secondary));
+        }
+        vec![event]
+    }
+}
 impl Slot {
     pub fn new(theme: &Theme, ty: SlotType) -> Self {
         let ghost = theme.colors().get(colors::Text::Secondary);
@@ -348,7 +702,7 @@ impl SlotType {
                     true => default.to_string(),
                     false => {
                         match format {
-                            InputFormat::Numerical => chars.chars().rev().collect::<Vec<char>>().chunks(3).map(|c| c.iter().collect::<String>()).collect::<Vec<_>>().join(",").chars().rev().collect::<String>(),
+                            InputFormat::Numerical(Some(grouping)) => chars.chars().rev().collect::<Vec<char>>().chunks(3).map(|c| c.iter().collect::<String>()).collect::<Vec<_>>().join(grouping.to_string().as_str()).chars().rev().collect::<String>(),
                             InputFormat::Date => chars.chars().rev().collect::<Vec<char>>().chunks(2).map(|c| c.iter().collect::<String>()).collect::<Vec<_>>().join("/").chars().rev().collect::<String>(),
                             _ => chars.to_string()
                         }
@@ -371,7 +725,88 @@ pub enum SlotVisual {
 
 #[derive(Eq, Clone, Debug, PartialEq)]
 pub enum InputFormat {
-    Numerical,
+    Numerical(Option<char>), // grouping separator
     Date,
     Time,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(s: &str) -> Decimal {s.parse().unwrap()}
+
+    fn convert(amount: &str, from: Denomination, to: Denomination, rate: Option<&str>, fiat: &NumberFormat) -> Option<String> {
+        let rate = rate.map(decimal);
+        let bitcoin = from.to_bitcoin(decimal(amount), rate)?;
+        Some(to.of_bitcoin(bitcoin, rate)?.round(to.format(fiat).decimals as u32)?.to_string())
+    }
+
+    #[test]
+    fn decimal_round_trips_through_strings() {
+        for s in ["0", "12", "12.50", "-0.05", "0.00000001"] {
+            assert_eq!(decimal(s).to_string(), s);
+        }
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!("".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn decimal_rounds_half_away_from_zero() {
+        assert_eq!(decimal("2.345").round(2), Some(decimal("2.35")));
+        assert_eq!(decimal("-2.345").round(2), Some(decimal("-2.35")));
+        assert_eq!(decimal("2.344").round(2), Some(decimal("2.34")));
+        assert_eq!(decimal("2.5").round(3), Some(decimal("2.500")));
+    }
+
+    #[test]
+    fn bitcoin_and_sats_convert_exactly() {
+        let fiat = NumberFormat::default();
+        assert_eq!(convert("0.1", Denomination::Bitcoin, Denomination::Sats, None, &fiat).as_deref(), Some("10000000"));
+        assert_eq!(convert("1", Denomination::Sats, Denomination::Bitcoin, None, &fiat).as_deref(), Some("0.00000001"));
+        assert_eq!(convert("0.3", Denomination::Bitcoin, Denomination::Bitcoin, None, &fiat).as_deref(), Some("0.30000000"));
+    }
+
+    #[test]
+    fn fiat_uses_the_rate_and_locale_decimals() {
+        let fiat = NumberFormat::default();
+        assert_eq!(convert("25", Denomination::Fiat, Denomination::Bitcoin, Some("50000"), &fiat).as_deref(), Some("0.00050000"));
+        assert_eq!(convert("0.0005", Denomination::Bitcoin, Denomination::Fiat, Some("50000"), &fiat).as_deref(), Some("25.00"));
+        assert_eq!(convert("100", Denomination::Fiat, Denomination::Sats, Some("30000"), &fiat).as_deref(), Some("333333"));
+
+        let yen = NumberFormat { currency: "¥".to_string(), decimals: 0, ..NumberFormat::default() };
+        assert_eq!(convert("0.001", Denomination::Bitcoin, Denomination::Fiat, Some("9876543.21"), &yen).as_deref(), Some("9877"));
+    }
+
+    #[test]
+    fn constraints_check_bounds_in_the_locale() {
+        let constraints = Constraints::new().min(1.5).max(1000.0);
+        let (english, german) = (Locale::new("en-US"), Locale::new("de-DE"));
+
+        assert_eq!(constraints.check(&decimal("1.5"), &english), Ok(()));
+        assert_eq!(constraints.check(&decimal("1000"), &english), Ok(()));
+        assert_eq!(constraints.check(&decimal("1.49"), &english), Err("Minimum is 1.5".to_string()));
+        assert_eq!(constraints.check(&decimal("1000.01"), &english), Err("Maximum is 1,000".to_string()));
+        assert_eq!(constraints.check(&decimal("1.49"), &german), Err("Minimum is 1,5".to_string()));
+    }
+
+    #[test]
+    fn constraints_run_the_validator_after_the_bounds() {
+        let constraints = Constraints::new().max(100.0).validator(|value| match value.mantissa % 5 == 0 {
+            true => Ok(()),
+            false => Err("Use multiples of 5".to_string()),
+        });
+        let locale = Locale::default();
+
+        assert_eq!(constraints.check(&decimal("25"), &locale), Ok(()));
+        assert_eq!(constraints.check(&decimal("26"), &locale), Err("Use multiples of 5".to_string()));
+        assert_eq!(constraints.check(&decimal("101"), &locale), Err("Maximum is 100".to_string()));
+    }
+
+    #[test]
+    fn fiat_needs_a_positive_rate() {
+        let fiat = NumberFormat::default();
+        assert_eq!(convert("25", Denomination::Fiat, Denomination::Bitcoin, None, &fiat), None);
+        assert_eq!(convert("25", Denomination::Fiat, Denomination::Bitcoin, Some("0"), &fiat), None);
+    }
+}
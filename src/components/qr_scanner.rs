@@ -127,7 +127,7 @@ impl QRGuide {
             Stack(Offset::Center, Offset::Center, Size::Static(308.0), Size::Static(308.0), Padding::default()), 
             Some(Rectangle::new(background, 8.0, None)), 
             Rectangle::new(Color::TRANSPARENT, 8.0, Some((4.0, outline))), 
-            Some(Message::new(theme, Icons::Camera, "Accessing device camera."))
+            Some(Message::new(theme, Icons::Camera, &theme.tr("camera-access")))
         )
     }
 
@@ -1,11 +1,13 @@
-use prism::event::OnEvent;
+use prism::event::{OnEvent, Event, KeyboardEvent, KeyboardState, Key, NamedKey};
 use prism::Context;
-use prism::drawable::Component;
+use prism::drawable::{Component, SizedTree};
 use prism::layout::Column;
 
 use ptsd::interactions;
 
 use crate::Callback;
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::FocusRing;
 use crate::theme::{Theme, Icons};
 use crate::components::list_item::ListItem;
 use crate::components::list_item::ListItemInfoLeft;
@@ -26,23 +28,51 @@ use crate::components::list_item::ListItemInfoLeft;
 /// ]);
 /// ```
 #[derive(Component, Debug, Clone)]
-pub struct RadioSelector(Column, pub Vec<interactions::Selectable>);
-impl OnEvent for RadioSelector {}
+pub struct RadioSelector(Column, pub Vec<interactions::Selectable>, #[skip] Vec<(String, String, Box<dyn Callback>)>, FocusRing, #[skip] uuid::Uuid, #[skip] Theme);
+impl OnEvent for RadioSelector {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(key), ..}) = event.downcast_ref::<KeyboardEvent>() && self.3.is_focused() && !self.1.is_empty() {
+            // Arrow keys move the selection within a focused group, like native radio buttons.
+            let count = self.1.len();
+            let next = match key {
+                NamedKey::ArrowDown | NamedKey::ArrowRight => (self.value() + 1) % count,
+                NamedKey::ArrowUp | NamedKey::ArrowLeft => (self.value() + count - 1) % count,
+                _ => return vec![event],
+            };
+            self.1 = RadioSelector::options(&self.5, next, &self.2, self.4);
+            (self.2[next].2)(ctx, &self.5);
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for RadioSelector {
+    fn accessibility(&self) -> AccessNode {
+        let options = self.1.iter().zip(&self.2).map(|(selectable, (title, _, _))| {
+            AccessNode::new(Role::RadioButton).label(title).checked(selectable.is_selected())
+        }).collect();
+        AccessNode::new(Role::RadioGroup).focused(self.3.is_focused()).children(options)
+    }
+}
+
 impl RadioSelector {
     pub fn new(theme: &Theme, index: usize, items: Vec<(&str, &str, Box<dyn Callback>)>) -> Self {
         let group_id = uuid::Uuid::new_v4();
-        let selectables = items.into_iter().enumerate().map(|(i, (t, s, mut c))| {
-            let title = t.to_string();
-            let selected = ListItem::new(theme, None, ListItemInfoLeft::new(&title.to_string(), Some(s), None, None), None, Some(Icons::RadioFilled), None, |_, _| {});
-            let default = ListItem::new(theme, None, ListItemInfoLeft::new(&title.to_string(), Some(s), None, None), None, Some(Icons::Radio), None, |_, _| {});
+        let items = items.into_iter().map(|(t, s, c)| (t.to_string(), s.to_string(), c)).collect::<Vec<_>>();
+        let selectables = RadioSelector::options(theme, index, &items, group_id);
+        RadioSelector(Column::center(0.0), selectables, items, FocusRing::underline(theme), group_id, theme.clone())
+    }
+
+    /// One selectable list item per option, with the option at `index` selected.
+    fn options(theme: &Theme, index: usize, items: &[(String, String, Box<dyn Callback>)], group_id: uuid::Uuid) -> Vec<interactions::Selectable> {
+        items.iter().enumerate().map(|(i, (title, subtitle, c))| {
+            let selected = ListItem::new(theme, None, ListItemInfoLeft::new(title, Some(subtitle), None, None), None, Some(Icons::RadioFilled), None, |_, _| {});
+            let default = ListItem::new(theme, None, ListItemInfoLeft::new(title, Some(subtitle), None, None), None, Some(Icons::Radio), None, |_, _| {});
 
-            let theme = theme.clone();
+            let (theme, mut c) = (theme.clone(), c.clone());
             let callback = Box::new(move |ctx: &mut Context| (c)(ctx, &theme));
             interactions::Selectable::new(default, selected, i == index, false, callback, group_id)
-        }).collect::<Vec<_>>();
-
-
-        RadioSelector(Column::center(0.0), selectables)
+        }).collect()
     }
 
     pub fn default(theme: &Theme) -> Self {
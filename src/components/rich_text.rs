@@ -0,0 +1,424 @@
+use prism::event::{OnEvent, Event, MouseEvent, MouseState};
+use prism::layout::{Area, Stack, Column, Offset, Size, Padding, SizeRequest};
+use prism::drawable::{Drawable, Component, SizedTree, RequestTree, Rect};
+use prism::canvas::{Align, Span, Text as BasicText, Area as CanvasArea, Item as CanvasItem};
+use prism::display::Bin;
+use prism::Context;
+
+use ptsd::{theme, FontStyle};
+
+use crate::theme::{Theme, Color, ThemeChanged};
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::components::{Rectangle, Paint};
+use crate::components::text::{ExpandableText, BulletedText, TextSize, TextStyle};
+
+/// ## Rich Text
+///
+/// Text with inline formatting from a small Markdown subset:
+/// `**bold**`, `*italic*` or `_italic_`, `` `code` ``, `[links](https://…)`, bare URLs and `@mentions`.
+/// A backslash escapes a marker, e.g. `\*`. Tapping a link or mention emits a [`RichTextEvent`].
+///
+/// Bold uses the theme's heading font. The theme has no italic or monospace faces,
+/// so italic runs use the medium weight and code is set apart by color.
+///
+/// ### Example
+/// ```rust
+/// let text = RichText::new(theme, "Ask @ella about the **new** [release notes](https://example.com/notes)", TextSize::Md, TextStyle::Primary, Align::Left, None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RichText {
+    inner: BasicText,
+    runs: Vec<Run>,
+    size: TextSize,
+    style: TextStyle,
+    surface: Surface,
+}
+
+impl Drawable for RichText {
+    fn request_size(&self) -> RequestTree {
+        let size = self.inner.size();
+        RequestTree(SizeRequest::new(0.0, size.1, f32::MAX, size.1), vec![])
+    }
+
+    fn draw(&self, sized: &SizedTree, offset: (f32, f32), bound: Rect) -> Vec<(CanvasArea, CanvasItem)> {
+        let text = BasicText {spans: self.inner.spans.clone(), width: Some(sized.0.0), align: self.inner.align, cursor: None, max_lines: self.inner.max_lines};
+        vec![(CanvasArea{offset, bounds: Some(bound)}, CanvasItem::Text(text))]
+    }
+
+    fn event(&mut self, ctx: &mut Context, sized: &SizedTree, event: Box<dyn Event>) {
+        self.inner.width = Some(sized.0.0);
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.inner.spans = RichText::spans(theme, &self.runs, self.size, self.style, self.surface);
+        } else if let Some(MouseEvent{state: MouseState::Pressed, position: Some((x, y)), ..}) = event.downcast_ref::<MouseEvent>() {
+            // The laid out text already maps a point to a character for the editor's caret.
+            self.inner.cursor_click(*x, *y);
+            let index = self.inner.cursor.take().unwrap_or_default();
+
+            let mut start = 0;
+            let tapped = self.runs.iter().find(|run| {
+                let end = start + run.text.chars().count();
+                let hit = (start..end).contains(&index);
+                start = end;
+                hit
+            });
+            match tapped.map(|run| &run.style) {
+                Some(RunStyle::Link(url)) => ctx.emit(RichTextEvent::Link(url.to_string())),
+                Some(RunStyle::Mention(name)) => ctx.emit(RichTextEvent::Mention(name.to_string())),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Accessible for RichText {
+    fn accessibility(&self) -> AccessNode {
+        AccessNode::new(Role::Label).label(self.plain())
+    }
+}
+
+impl RichText {
+    pub fn new(theme: &Theme, markup: &str, size: TextSize, style: TextStyle, align: Align, max_lines: Option<u32>) -> Self {
+        Self::from_runs(theme, parse(markup), size, style, align, max_lines)
+    }
+
+    pub fn from_runs(theme: &Theme, runs: Vec<Run>, size: TextSize, style: TextStyle, align: Align, max_lines: Option<u32>) -> Self {
+        let inner = BasicText::new(RichText::spans(theme, &runs, size, style, Surface::default()), None, align, max_lines);
+        RichText { inner, runs, size, style, surface: Surface::default() }
+    }
+
+    /// Recolors links and mentions to stay readable on `surface`.
+    pub fn on(mut self, theme: &Theme, surface: Surface) -> Self {
+        self.surface = surface;
+        self.inner.spans = RichText::spans(theme, &self.runs, self.size, self.style, surface);
+        self
+    }
+
+    pub fn runs(&self) -> &[Run] {&self.runs}
+
+    pub fn inner(&self) -> &BasicText {&self.inner}
+
+    /// The text without markup.
+    pub fn plain(&self) -> String {
+        self.runs.iter().map(|run| run.text.as_str()).collect()
+    }
+
+    fn spans(theme: &Theme, runs: &[Run], size: TextSize, style: TextStyle, surface: Surface) -> Vec<Span> {
+        let (color, font) = style.get(theme);
+        let fonts = theme.fonts();
+        let colors = theme.colors();
+        let size = theme.font_size(size);
+        let accent = match surface {
+            Surface::Brand => colors.get(ptsd::Brand).contrasted(),
+            Surface::Background | Surface::Secondary => colors.get(ptsd::Brand),
+        };
+
+        runs.iter().map(|run| {
+            let (color, font) = match &run.style {
+                RunStyle::Plain => (color, font.clone()),
+                RunStyle::Bold => (color, fonts.get_font(FontStyle::Heading).unwrap().clone()),
+                RunStyle::Italic => (color, fonts.get_font(FontStyle::Label).unwrap().clone()),
+                RunStyle::Code => (colors.get(theme::Text::Secondary), fonts.get_font(FontStyle::Label).unwrap().clone()),
+                RunStyle::Link(_) | RunStyle::Mention(_) => (accent, fonts.get_font(FontStyle::Label).unwrap().clone()),
+            };
+            Span::new(run.text.to_string(), size, Some(size*1.25), font.into(), color.into(), 0.0)
+        }).collect()
+    }
+}
+
+/// ## Markdown
+///
+/// Paragraphs of [`RichText`], bullet lists (`- `, `* ` or `• `) as [`BulletedText`] and fenced code
+/// blocks (```` ``` ````). Bare `http://` and `https://` URLs become links.
+///
+/// ### Example
+/// ```rust
+/// let notes = Markdown::new(theme, "Shipped:\n- **dark mode**\n- `TextArea`\n\nSee https://example.com", TextSize::Md, TextStyle::Primary, Surface::Background);
+/// ```
+#[derive(Debug, Component, Clone)]
+pub struct Markdown(Column, Vec<MarkdownBlock>);
+impl OnEvent for Markdown {}
+
+impl Markdown {
+    pub fn new(theme: &Theme, markdown: &str, size: TextSize, style: TextStyle, surface: Surface) -> Self {
+        let align = theme.locale().start();
+        let mut blocks = vec![];
+        let mut paragraph: Vec<&str> = vec![];
+        let mut bullets: Vec<&str> = vec![];
+        let mut code: Option<Vec<&str>> = None;
+
+        let flush = |paragraph: &mut Vec<&str>, bullets: &mut Vec<&str>, blocks: &mut Vec<MarkdownBlock>| {
+            if !paragraph.is_empty() {
+                blocks.push(MarkdownBlock::paragraph(theme, &paragraph.join("\n"), size, style, align, surface));
+                paragraph.clear();
+            }
+            if !bullets.is_empty() {
+                blocks.push(MarkdownBlock::bullets(theme, std::mem::take(bullets), size, style, surface));
+            }
+        };
+
+        for line in markdown.lines() {
+            if line.trim_start().starts_with("```") {
+                flush(&mut paragraph, &mut bullets, &mut blocks);
+                match code.take() {
+                    Some(lines) => blocks.push(MarkdownBlock::code(theme, &lines.join("\n"), size, align, surface)),
+                    None => code = Some(vec![]),
+                }
+            } else if let Some(lines) = &mut code {
+                lines.push(line);
+            } else if let Some(item) = ["- ", "* ", "• "].iter().find_map(|b| line.trim_start().strip_prefix(b)) {
+                // Consecutive items share one list; a list ends a paragraph and vice versa.
+                if !paragraph.is_empty() { flush(&mut paragraph, &mut bullets, &mut blocks); }
+                bullets.push(item);
+            } else if line.trim().is_empty() {
+                flush(&mut paragraph, &mut bullets, &mut blocks);
+            } else {
+                if !bullets.is_empty() { flush(&mut paragraph, &mut bullets, &mut blocks); }
+                paragraph.push(line);
+            }
+        }
+
+        // An unclosed fence still shows its lines as code.
+        if let Some(lines) = code { blocks.push(MarkdownBlock::code(theme, &lines.join("\n"), size, align, surface)); }
+        flush(&mut paragraph, &mut bullets, &mut blocks);
+
+        Markdown(Column::new(8.0, Offset::Start, Size::Fill, Padding::default(), None), blocks)
+    }
+
+    /// The width of the widest block as laid out.
+    pub fn width(&self) -> f32 {
+        self.1.iter().map(|block| match block {
+            MarkdownBlock::Paragraph {text, ..} => text.inner().size().0,
+            MarkdownBlock::List {list, ..} => list.width(),
+            MarkdownBlock::Code {text, ..} => text.inner().0.inner().size().0 + 16.0,
+        }).fold(0.0, f32::max)
+    }
+}
+
+/// One block of [`Markdown`].
+#[derive(Debug, Component, Clone)]
+pub enum MarkdownBlock {
+    Paragraph {layout: Stack, text: RichText},
+    List {layout: Stack, list: BulletedText},
+    Code {layout: Stack, background: Rectangle, text: Bin<Stack, ExpandableText>},
+}
+
+impl OnEvent for MarkdownBlock {}
+
+impl MarkdownBlock {
+    fn paragraph(theme: &Theme, text: &str, size: TextSize, style: TextStyle, align: Align, surface: Surface) -> Self {
+        MarkdownBlock::Paragraph {
+            layout: Stack(Offset::Start, Offset::Start, Size::Fill, Size::Fit, Padding::default()),
+            text: RichText::new(theme, text, size, style, align, None).on(theme, surface),
+        }
+    }
+
+    fn bullets(theme: &Theme, items: Vec<&str>, size: TextSize, style: TextStyle, surface: Surface) -> Self {
+        MarkdownBlock::List {
+            layout: Stack(Offset::Start, Offset::Start, Size::Fill, Size::Fit, Padding::default()),
+            list: BulletedText::rich(theme, items, size, style, surface),
+        }
+    }
+
+    fn code(theme: &Theme, code: &str, size: TextSize, align: Align, surface: Surface) -> Self {
+        MarkdownBlock::Code {
+            layout: Stack(Offset::Start, Offset::Start, Size::Fill, Size::Fit, Padding::default()),
+            background: Rectangle::themed(theme, Paint::new(move |theme| surface.inset(theme)), 8.0, None),
+            text: Bin(
+                Stack(Offset::Start, Offset::Start, Size::Fill, Size::Fit, Padding(8.0, 8.0, 8.0, 8.0)),
+                ExpandableText::new(theme, code, size, TextStyle::Primary, align, None)
+            ),
+        }
+    }
+}
+
+/// What text is drawn on, so links, mentions and code blocks stay readable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Surface {
+    #[default]
+    Background,
+    /// A secondary background, such as a received message bubble.
+    Secondary,
+    /// The brand color, such as a sent message bubble.
+    Brand,
+}
+
+impl Surface {
+    /// A background that stands out on this surface, for code blocks and cards.
+    pub fn inset(&self, theme: &Theme) -> Color {
+        match self {
+            Surface::Background | Surface::Brand => theme.colors().get(ptsd::Background::Secondary),
+            Surface::Secondary => theme.colors().get(ptsd::Background::Primary),
+        }
+    }
+}
+
+/// A piece of [`RichText`] in one style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub text: String,
+    pub style: RunStyle,
+}
+
+impl Run {
+    pub fn new(text: &str, style: RunStyle) -> Self {
+        Run { text: text.to_string(), style }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RunStyle {
+    Plain,
+    Bold,
+    Italic,
+    Code,
+    /// Carries the link's URL.
+    Link(String),
+    /// Carries the name without the "@".
+    Mention(String),
+}
+
+/// Emitted when a link or mention in a [`RichText`] is tapped.
+#[derive(Debug, Clone)]
+pub enum RichTextEvent {
+    Link(String),
+    Mention(String),
+}
+
+impl Event for RichTextEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: &[Area]) -> Vec<Option<Box<dyn Event>>> {
+        children.iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Splits `markup` into styled runs. Markers without a closing pair are kept as text.
+pub fn parse(markup: &str) -> Vec<Run> {
+    let chars: Vec<char> = markup.chars().collect();
+    let mut runs: Vec<Run> = vec![];
+    let mut plain = String::new();
+    let mut i = 0;
+
+    let find = |from: usize, marker: &[char]| (from..chars.len()).find(|j| chars[*j..].starts_with(marker) && chars[j - 1] != '\\');
+    let text = |from: usize, to: usize| chars[from..to].iter().collect::<String>();
+
+    while i < chars.len() {
+        let styled = match chars[i] {
+            '\\' if i + 1 < chars.len() => {
+                plain.push(chars[i + 1]);
+                i += 2;
+                continue;
+            },
+            '*' if chars[i..].starts_with(&['*', '*']) => find(i + 2, &['*', '*'])
+                .filter(|end| *end > i + 2)
+                .map(|end| (Run::new(&unescape(&text(i + 2, end)), RunStyle::Bold), end + 2)),
+            // Underscores inside a word, as in snake_case, stay literal.
+            '_' if i > 0 && chars[i - 1].is_alphanumeric() => None,
+            '*' | '_' => find(i + 1, &[chars[i]])
+                .filter(|end| *end > i + 1)
+                .map(|end| (Run::new(&unescape(&text(i + 1, end)), RunStyle::Italic), end + 1)),
+            '`' => find(i + 1, &['`'])
+                .filter(|end| *end > i + 1)
+                .map(|end| (Run::new(&text(i + 1, end), RunStyle::Code), end + 1)),
+            '[' => find(i + 1, &[']', '(']).and_then(|close| {
+                let end = find(close + 2, &[')'])?;
+                Some((Run::new(&unescape(&text(i + 1, close)), RunStyle::Link(text(close + 2, end))), end + 1))
+            }),
+            'h' if (i == 0 || chars[i - 1].is_whitespace() || chars[i - 1] == '(') && ["https://", "http://"].iter().any(|p| chars[i..].starts_with(&p.chars().collect::<Vec<_>>())) => {
+                let url: String = chars[i..].iter().take_while(|c| !c.is_whitespace()).collect();
+                let url = url.trim_end_matches(['.', ',', '!', '?', ';', ':', ')']).to_string();
+                Some((Run::new(&url, RunStyle::Link(url.clone())), i + url.chars().count()))
+            },
+            '@' if i == 0 || chars[i - 1].is_whitespace() => {
+                let name: String = chars[i + 1..].iter().take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-')).collect();
+                let name = name.trim_end_matches(['.', '-']).to_string();
+                (!name.is_empty()).then(|| (Run::new(&format!("@{name}"), RunStyle::Mention(name.clone())), i + 1 + name.chars().count()))
+            },
+            _ => None,
+        };
+
+        match styled {
+            Some((run, next)) => {
+                if !plain.is_empty() { runs.push(Run::new(&std::mem::take(&mut plain), RunStyle::Plain)); }
+                runs.push(run);
+                i = next;
+            },
+            None => {
+                plain.push(chars[i]);
+                i += 1;
+            }
+        }
+    }
+
+    if !plain.is_empty() { runs.push(Run::new(&plain, RunStyle::Plain)); }
+    runs
+}
+
+fn unescape(text: &str) -> String {
+    let mut chars = text.chars().peekable();
+    let mut out = String::new();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some(_)) => out.extend(chars.next()),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runs(markup: &str) -> Vec<(String, RunStyle)> {
+        parse(markup).into_iter().map(|run| (run.text, run.style)).collect()
+    }
+
+    fn run(text: &str, style: RunStyle) -> (String, RunStyle) {(text.to_string(), style)}
+
+    #[test]
+    fn parses_inline_styles() {
+        assert_eq!(runs("a **bold** _it_ *also* `co*de*`"), vec![
+            run("a ", RunStyle::Plain),
+            run("bold", RunStyle::Bold),
+            run(" ", RunStyle::Plain),
+            run("it", RunStyle::Italic),
+            run(" ", RunStyle::Plain),
+            run("also", RunStyle::Italic),
+            run(" ", RunStyle::Plain),
+            run("co*de*", RunStyle::Code),
+        ]);
+    }
+
+    #[test]
+    fn leaves_unmatched_and_escaped_markers_literal() {
+        assert_eq!(runs("2 * 3 = 6"), vec![run("2 * 3 = 6", RunStyle::Plain)]);
+        assert_eq!(runs("snake_case_name"), vec![run("snake_case_name", RunStyle::Plain)]);
+        assert_eq!(runs(r"\*not italic\*"), vec![run("*not italic*", RunStyle::Plain)]);
+        assert_eq!(runs("****"), vec![run("****", RunStyle::Plain)]);
+    }
+
+    #[test]
+    fn parses_links_and_mentions() {
+        assert_eq!(runs("see [the docs](https://example.com/a) or ask @ada.l."), vec![
+            run("see ", RunStyle::Plain),
+            run("the docs", RunStyle::Link("https://example.com/a".to_string())),
+            run(" or ask ", RunStyle::Plain),
+            run("@ada.l", RunStyle::Mention("ada.l".to_string())),
+            run(".", RunStyle::Plain),
+        ]);
+        assert_eq!(runs("mail ada@example.com"), vec![run("mail ada@example.com", RunStyle::Plain)]);
+    }
+
+    #[test]
+    fn auto_links_bare_urls_without_trailing_punctuation() {
+        assert_eq!(runs("Go to https://example.com/x?y=1."), vec![
+            run("Go to ", RunStyle::Plain),
+            run("https://example.com/x?y=1", RunStyle::Link("https://example.com/x?y=1".to_string())),
+            run(".", RunStyle::Plain),
+        ]);
+        assert_eq!(runs("(http://a.b)"), vec![
+            run("(", RunStyle::Plain),
+            run("http://a.b", RunStyle::Link("http://a.b".to_string())),
+            run(")", RunStyle::Plain),
+        ]);
+    }
+}
@@ -6,7 +6,7 @@ use prism::layout::{Area, Wrap, Stack, Column};
 use ptsd::interactions;
 
 use crate::Callback;
-use crate::theme::{Theme, Icons};
+use crate::theme::{Theme, Icons, ThemeChanged};
 use crate::components::TextInput;
 use crate::components::list_item::ListItem;
 use crate::components::button::{SecondaryButton, QuickActions};
@@ -40,7 +40,9 @@ impl SearchBar {
 pub struct SelectedItems(Wrap, Vec<SearchPill>, #[skip] Theme);
 impl OnEvent for SelectedItems {
     fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
-        if let Some(event) = event.downcast_ref::<SearchbarEvent>() {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.2 = theme.clone();
+        } else if let Some(event) = event.downcast_ref::<SearchbarEvent>() {
             match event {
                 SearchbarEvent::Remove(id) => {
                     let id = id.clone();
@@ -79,7 +81,9 @@ impl SelectedItems {
 pub struct SearchableItems(Column, Vec<SearchBarListItem>, #[skip] Theme);
 impl OnEvent for SearchableItems {
     fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
-        if let Some(event) = event.downcast_ref::<SearchbarEvent>() {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.2 = theme.clone();
+        } else if let Some(event) = event.downcast_ref::<SearchbarEvent>() {
             match event {
                 SearchbarEvent::Select(id) => {
                     let id = id.clone();
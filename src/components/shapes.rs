@@ -1,24 +1,58 @@
 use prism::drawable::{Drawable, Component, RequestTree, SizedTree, Offset, Rect};
 use prism::canvas::{ShapeType, Shape, Area as CanvasArea, Item as CanvasItem, self};
-use prism::event::OnEvent;
+use prism::event::{OnEvent, Event};
 use prism::layout::SizeRequest;
 use prism::layout::Stack;
+use prism::Context;
 
-use crate::theme::Color;
+use std::sync::{Arc, Mutex};
+
+use crate::Shared;
+use crate::theme::{Theme, Color, ThemeChanged};
+
+/// A color looked up in the active [`Theme`], so a component can resolve it
+/// again when it receives [`ThemeChanged`].
+///
+/// ```rust
+/// let card = Rectangle::themed(theme, Paint::new(|theme| theme.colors().get(ptsd::Background::Secondary)), 8.0, None);
+/// ```
+pub type Paint = Shared<dyn Fn(&Theme) -> Color + Send + Sync>;
+
+impl Paint {
+    pub fn new(color: impl Fn(&Theme) -> Color + Send + Sync + 'static) -> Self {Shared(Arc::new(Mutex::new(color)))}
+    pub fn get(&self, theme: &Theme) -> Color {(self.lock())(theme)}
+}
 
 #[derive(Debug, Component, Clone)]
-pub struct Rectangle(Stack, ExpandableShape, Option<ExpandableShape>);
-impl OnEvent for Rectangle {}
+pub struct Rectangle(Stack, ExpandableShape, Option<ExpandableShape>, #[skip] Option<(Paint, Option<Paint>)>);
+
+impl OnEvent for Rectangle {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() && let Some((background, outline)) = &self.3 {
+            self.1.shape().color = background.get(theme).into();
+            if let (Some(shape), Some(outline)) = (&mut self.2, outline) { shape.shape().color = outline.get(theme).into(); }
+        }
+        vec![event]
+    }
+}
 
 impl Rectangle {
     pub fn new(background: Color, radius: f32, outline: Option<(f32, Color)>) -> Self {
         Rectangle(
             Stack::default(),
             ExpandableShape::rounded_rectangle(0.0, radius, background),
-            outline.map(|(s, c)| ExpandableShape::rounded_rectangle(s, radius, c))
+            outline.map(|(s, c)| ExpandableShape::rounded_rectangle(s, radius, c)),
+            None,
         )
     }
 
+    /// A rectangle whose colors follow [`ThemeChanged`].
+    pub fn themed(theme: &Theme, background: Paint, radius: f32, outline: Option<(f32, Paint)>) -> Self {
+        let mut rectangle = Rectangle::new(background.get(theme), radius, outline.as_ref().map(|(s, c)| (*s, c.get(theme))));
+        rectangle.3 = Some((background, outline.map(|(_, c)| c)));
+        rectangle
+    }
+
     pub fn background(&mut self) -> &mut canvas::Color {&mut self.1.shape().color}
     pub fn outline(&mut self) -> Option<&mut canvas::Color> {self.2.as_mut().map(|s| &mut s.shape().color)}
     pub fn size(&self) -> (f32, f32) {self.1.0.shape.size()}
@@ -1,12 +1,17 @@
-use prism::event::OnEvent;
+use prism::event::{OnEvent, Event, KeyboardEvent, KeyboardState, Key, NamedKey};
 use prism::canvas::Align;
 use prism::Context;
-use prism::drawable::Component;
+use prism::drawable::{Component, SizedTree};
 use prism::layout::Column;
 
 use ptsd::interactions;
 
-use crate::theme::Theme;
+use std::sync::{Arc, Mutex};
+
+use crate::Shared;
+use crate::theme::{Theme, ThemeChanged};
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::FocusRing;
 use crate::components::text::{TextSize, TextStyle, ExpandableText, Text};
 use crate::components::{Circle, Rectangle};
 
@@ -31,8 +36,37 @@ use crate::components::{Circle, Rectangle};
 /// );
 /// ```
 #[derive(Debug, Component, Clone)]
-pub struct Slider(Column, Option<Text>, Option<ExpandableText>, interactions::Slider); // last f32 = value 0.0..1.0
-impl OnEvent for Slider {}
+pub struct Slider(Column, Option<Text>, Option<ExpandableText>, interactions::Slider, #[skip] Arc<Mutex<f32>>, FocusRing, #[skip] OnSlide, #[skip] Theme); // last f32 = value 0.0..1.0
+impl OnEvent for Slider {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(key), ..}) = event.downcast_ref::<KeyboardEvent>() && self.5.is_focused() {
+            let step = match key {
+                NamedKey::ArrowRight | NamedKey::ArrowUp => 0.05,
+                NamedKey::ArrowLeft | NamedKey::ArrowDown => -0.05,
+                _ => return vec![event],
+            };
+            let value = (*self.4.lock().unwrap() + step).clamp(0.0, 1.0);
+            self.3 = Slider::track(&self.7, value, &self.6);
+            self.6.call(ctx, value);
+        } else if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.7 = theme.clone();
+            self.3 = Slider::track(&self.7, *self.4.lock().unwrap(), &self.6);
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for Slider {
+    fn accessibility(&self) -> AccessNode {
+        let value = *self.4.lock().unwrap();
+        let node = AccessNode::new(Role::Slider).value(format!("{}%", (value * 100.0).round())).numeric(value as f64, 0.0, 1.0).focused(self.5.is_focused());
+        match &self.1 {
+            Some(label) => node.label(&label.spans[0]),
+            None => node,
+        }
+    }
+}
+
 impl Slider {
     pub fn new(
         theme: &Theme,
@@ -41,19 +75,37 @@ impl Slider {
         description: Option<&str>,
         mut on_change: impl FnMut(&mut Context, &Theme, f32) + Clone + Send + Sync + 'static,
     ) -> Self {
-        let colors = theme.colors();
-        let background = Rectangle::new(colors.get(ptsd::Outline::Primary), 3.0, None);
-        let foreground = Rectangle::new(colors.get(ptsd::Brand), 3.0, None);
-        let handle = Circle::new(18.0, colors.get(ptsd::Brand), false);
         let label = label.map(|l| Text::new(theme, l, TextSize::H5, TextStyle::Heading, Align::Left, None));
         let description = description.map(|t| ExpandableText::new(theme, t, TextSize::Md, TextStyle::Primary, Align::Left, None));
 
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context, p: f32| (on_change)(ctx, &theme, p));
-        Slider(Column::start(8.0), label, description, interactions::Slider::new(start, background, foreground, handle, callback))
+        let value = Arc::new(Mutex::new(start));
+        let (callback_theme, shared) = (theme.clone(), value.clone());
+        let on_slide = OnSlide::new(move |ctx: &mut Context, p: f32| {
+            *shared.lock().unwrap() = p;
+            (on_change)(ctx, &callback_theme, p)
+        });
+        let track = Slider::track(theme, start, &on_slide);
+        Slider(Column::start(8.0), label, description, track, value, FocusRing::underline(theme), on_slide, theme.clone())
     }
 
     pub fn default(theme: &Theme) -> Self {
         Self::new(theme, 0.5, Some("Slider"), None, |_: &mut Context, _: &Theme, p: f32| println!("Slider moved... {p:?}"))
     }
-}
\ No newline at end of file
+
+    fn track(theme: &Theme, value: f32, on_slide: &OnSlide) -> interactions::Slider {
+        let colors = theme.colors();
+        let background = Rectangle::new(colors.get(ptsd::Outline::Primary), 3.0, None);
+        let foreground = Rectangle::new(colors.get(ptsd::Brand), 3.0, None);
+        let handle = Circle::new(18.0, colors.get(ptsd::Brand), false);
+        let on_slide = on_slide.clone();
+        let callback = Box::new(move |ctx: &mut Context, p: f32| on_slide.call(ctx, p));
+        interactions::Slider::new(value, background, foreground, handle, callback)
+    }
+}
+
+type OnSlide = Shared<dyn FnMut(&mut Context, f32) + Send + Sync>;
+
+impl OnSlide {
+    fn new(callback: impl FnMut(&mut Context, f32) + Send + Sync + 'static) -> Self {Shared(Arc::new(Mutex::new(callback)))}
+    fn call(&self, ctx: &mut Context, value: f32) {(self.lock())(ctx, value)}
+}
@@ -1,17 +1,21 @@
-use prism::event::{OnEvent, MouseState, MouseEvent, Event, TickEvent, Key, NamedKey, KeyboardEvent, KeyboardState};
-use prism::layout::{Stack, Size, Offset, Padding, SizeRequest};
-use prism::display::Opt;
+use prism::event::{OnEvent, MouseState, MouseEvent, Event, TickEvent, Key, NamedKey, KeyboardEvent, KeyboardState, Modifiers};
+use prism::layout::{Area, Stack, Column, Row, Size, Offset, Padding, SizeRequest};
+use prism::display::{Bin, Opt};
 use prism::drawable::{Drawable, Component, SizedTree, RequestTree, Rect}; 
-use prism::canvas::{self, Align, Span, Text as BasicText, Area as CanvasArea, Item as CanvasItem};
+use prism::canvas::{self, Align, Span, Shape, Text as BasicText, Area as CanvasArea, Item as CanvasItem};
 use prism::Context;
 
-use pelican_ui::components::Rectangle;
-use pelican_ui::theme::{Theme, Color};
+use pelican_ui::components::{Rectangle, Circle, RichText, Surface};
+use pelican_ui::locale::Directional;
+use pelican_ui::theme::{Theme, Color, ThemeChanged};
+use pelican_ui::accessibility::{Accessible, AccessNode, Role};
 
 use ptsd::{theme, FontStyle};
 pub use ptsd::TextSize;
 
-#[derive(Component, Debug, Clone)]
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Component, Clone)]
 pub struct Text {
     layout: Stack,
     inner: BasicText,
@@ -21,20 +25,41 @@ pub struct Text {
     #[skip] pub align: Align,
     #[skip] pub max_lines: Option<u32>,
     #[skip] pub kerning: f32,
+    #[skip] pub mask: Option<Mask>,
+    #[skip] theme: Option<Theme>,
+}
+
+impl std::fmt::Debug for Text {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Masked text is sensitive, so neither the spans nor the laid out text are printed.
+        match self.mask {
+            Some(_) => f.debug_struct("Text").field("spans", &"<redacted>").field("size", &self.size).field("style", &self.style).finish(),
+            None => f.debug_struct("Text").field("layout", &self.layout).field("inner", &self.inner).field("spans", &self.spans)
+                .field("size", &self.size).field("style", &self.style).field("align", &self.align).field("max_lines", &self.max_lines)
+                .field("kerning", &self.kerning).field("theme", &self.theme).finish(),
+        }
+    }
 }
 
 impl OnEvent for Text {
     fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
-        if event.downcast_ref::<TickEvent>().is_some() {
-            // let (color, font) = self.style.get(ctx);
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.theme = Some(theme.clone());
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            let resolved = self.theme.take().map(|theme| (self.style.get(&theme), theme.font_size(self.size)));
             self.inner.align = self.align;
             self.inner.max_lines = self.max_lines;
             self.inner.spans.iter_mut().enumerate().for_each(|(i, s)| {
-                s.text = self.spans[i].to_string();
-                // s.font_size = self.size.get(ctx);
+                s.text = match &self.mask {
+                    Some(mask) => mask.apply(&self.spans[i]),
+                    None => self.spans[i].to_string(),
+                };
+                if let Some(((color, font), size)) = &resolved {
+                    s.font_size = *size;
+                    s.color = (*color).into();
+                    s.font = font.clone().into();
+                }
                 s.line_height = Some(s.font_size * 1.25);
-                // s.color = color.into();
-                // s.font = font.clone().into();
                 s.kerning = self.kerning;
             });
         }
@@ -42,12 +67,19 @@ impl OnEvent for Text {
     }
 }
 
+impl Accessible for Text {
+    fn accessibility(&self) -> AccessNode {
+        let text = self.spans.concat();
+        AccessNode::new(Role::Label).label(self.mask.as_ref().map(|m| m.apply(&text)).unwrap_or(text))
+    }
+}
+
 impl Text {
     pub fn new(theme: &Theme, text: &str, text_size: TextSize, style: TextStyle, align: Align, max_lines: Option<u32>) -> Self {
         let (color, font) = style.get(theme);
-        let size = theme.fonts().get_size(text_size);
+        let size = theme.font_size(text_size);
         let inner = BasicText::new(vec![Span::new(text.to_string(), size, Some(size*1.25), font.into(), color.into(), 0.0)], None, align, max_lines);
-        Text {layout: Stack::default(), inner, spans: vec![text.to_string()], size: text_size, style, align, max_lines, kerning: 0.0}
+        Text {layout: Stack::default(), inner, spans: vec![text.to_string()], size: text_size, style, align, max_lines, kerning: 0.0, mask: None, theme: None}
     }
 
     pub fn default(theme: &Theme, text: &str) -> Self {
@@ -58,6 +90,41 @@ impl Text {
 }
 
 
+/// Hides a [`Text`]'s characters behind a symbol, e.g. for passwords.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mask {
+    pub symbol: char,
+    /// Briefly shows each character as it is typed.
+    pub reveal_last: bool,
+    /// The character index currently shown and the ticks left to show it.
+    revealed: Option<(usize, u32)>,
+}
+
+impl Mask {
+    /// About a second at 60 ticks per second.
+    const REVEAL_TICKS: u32 = 60;
+
+    pub fn new(reveal_last: bool) -> Self {
+        Mask { symbol: '•', reveal_last, revealed: None }
+    }
+
+    /// One symbol per grapheme, so "é" or a flag emoji masks as a single bullet.
+    pub fn apply(&self, text: &str) -> String {
+        text.graphemes(true).enumerate().map(|(i, g)| match self.revealed {
+            Some((shown, _)) if shown == i => g.to_string(),
+            _ => self.symbol.to_string(),
+        }).collect()
+    }
+
+    fn typed(&mut self, index: usize) {
+        self.revealed = self.reveal_last.then_some((index, Self::REVEAL_TICKS));
+    }
+
+    fn tick(&mut self) {
+        self.revealed = self.revealed.and_then(|(i, ticks)| (ticks > 1).then_some((i, ticks - 1)));
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum TextStyle {
     Heading,
@@ -98,6 +165,10 @@ impl ExpandableText {
     }
 }
 
+impl Accessible for ExpandableText {
+    fn accessibility(&self) -> AccessNode {self.0.accessibility()}
+}
+
 impl Drawable for ExpandableText {
     fn request_size(&self) -> RequestTree {
         let size = self.0.inner.size();
@@ -115,92 +186,399 @@ impl Drawable for ExpandableText {
     }
 }
 
+/// ## Text Editor
+///
+/// Editable text with a cursor, a selection and an undo history.
+///
+/// Positions count grapheme clusters, so an accented letter, a flag or a ZWJ emoji
+/// sequence is stepped over, selected and deleted as one character.
+///
+/// Shift with the arrows, Home or End extends the selection; Ctrl (or Cmd) with
+/// the arrows moves by word. Ctrl+A, C, X and V select all, copy, cut and paste
+/// through the system clipboard, and Ctrl+Z, Ctrl+Shift+Z and Ctrl+Y undo and redo.
+/// The same actions are available to on-screen controls through [`EditEvent`].
 #[derive(Component, Clone)]
-pub struct TextEditor(Stack, pub ExpandableText, TextCursor);
+pub struct TextEditor(Stack, pub ExpandableText, TextCursor, Highlight, #[skip] Editing);
 impl std::fmt::Debug for TextEditor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The undo history is left out; it holds earlier values of possibly secure text.
         f.debug_tuple("TextEditor").field(&self.0).field(&self.1).field(&self.2).finish()
     }
 }
 
 impl TextEditor {
     pub fn new(theme: &Theme, text: &str, size: TextSize, style: TextStyle, align: Align) -> Self {
-        let mut built = ExpandableText::new(theme, text, size, style, align, None);
-        built.0.inner.cursor = Some(text.len());
-        TextEditor(Stack::start(), built, TextCursor::new(theme, style, size))
+        let built = ExpandableText::new(theme, text, size, style, align, None);
+        let mut editor = TextEditor(Stack::start(), built, TextCursor::new(theme, style, size), Highlight::new(theme), Editing::default());
+        editor.set_cursor(text.graphemes(true).count());
+        editor
     }
 
     pub fn default(theme: &Theme) -> Self {
         Self::new(theme, "", TextSize::Md, TextStyle::Primary, Align::Left)
     }
 
+    /// Shows the cursor; only an editor showing its cursor handles [`EditEvent`]s.
     pub fn display_cursor(&mut self, display: bool) {
+        self.4.focused = display;
         self.2.1.display(display)
     }
+
+    /// Masks the text with bullets, see [`Mask`]; `None` shows it in plain text.
+    /// Masked text can't be copied or cut.
+    pub fn secure(&mut self, mask: Option<Mask>) {
+        self.1.0.mask = mask;
+    }
+
+    pub fn is_secure(&self) -> bool {self.1.0.mask.is_some()}
+
+    /// The selected range of graphemes, start first.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let cursor = self.cursor();
+        self.4.anchor.filter(|a| *a != cursor).map(|a| (a.min(cursor), a.max(cursor)))
+    }
+
+    pub fn selected(&self) -> Option<String> {
+        self.selection().map(|(start, end)| self.1.0.spans[0].graphemes(true).skip(start).take(end - start).collect())
+    }
+
+    pub fn select_all(&mut self) {
+        self.4.anchor = Some(0);
+        self.set_cursor(self.1.0.spans[0].graphemes(true).count());
+    }
+
+    pub fn undo(&mut self) {
+        if let Some((text, cursor)) = self.4.undo.pop() {
+            let current = (self.1.0.spans[0].clone(), self.cursor());
+            self.4.redo.push(current);
+            self.restore(text, cursor);
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some((text, cursor)) = self.4.redo.pop() {
+            let current = (self.1.0.spans[0].clone(), self.cursor());
+            self.4.undo.push(current);
+            self.restore(text, cursor);
+        }
+    }
+
+    /// The cursor as a grapheme index, kept within the text if it was replaced from outside.
+    fn cursor(&self) -> usize {self.4.cursor.min(self.1.0.spans[0].graphemes(true).count())}
+
+    /// Moves the cursor and the laid out text's caret with it. The caret counts the
+    /// characters of the shown text, which for masked text is one bullet per grapheme.
+    fn set_cursor(&mut self, cursor: usize) {
+        self.4.cursor = cursor;
+        self.1.0.inner.cursor = Some(self.caret(cursor));
+    }
+
+    fn caret(&self, cursor: usize) -> usize {
+        match self.is_secure() {
+            true => cursor,
+            false => self.1.0.spans[0].graphemes(true).take(cursor).map(|g| g.chars().count()).sum(),
+        }
+    }
+
+    /// Snaps the laid out text's caret, e.g. after a click, to the nearest grapheme boundary.
+    fn sync_cursor(&mut self) {
+        let caret = self.1.0.inner.cursor.unwrap_or_default();
+        let cursor = match self.is_secure() {
+            true => caret,
+            false => {
+                let mut chars = 0;
+                self.1.0.spans[0].graphemes(true).take_while(|g| {
+                    let count = g.chars().count();
+                    let before = chars + count / 2 < caret;
+                    chars += count;
+                    before
+                }).count()
+            }
+        };
+        self.set_cursor(cursor);
+    }
+
+    fn restore(&mut self, text: String, cursor: usize) {
+        self.1.0.spans[0] = text;
+        self.set_cursor(cursor);
+        self.4.anchor = None;
+        self.4.last = None;
+    }
+
+    /// Moves the cursor, extending the selection from where it was when `extend` is set.
+    fn move_to(&mut self, cursor: usize, extend: bool) {
+        match extend {
+            true => { self.4.anchor.get_or_insert(self.cursor()); },
+            false => self.4.anchor = None,
+        }
+        self.set_cursor(cursor);
+    }
+
+    /// Replaces the characters in `start..end` with `text`, leaving the cursor after it.
+    fn replace(&mut self, (start, end): (usize, usize), text: &str, kind: EditKind) {
+        let graphemes: Vec<&str> = self.1.0.spans[0].graphemes(true).collect();
+        let (start, end) = (start.min(graphemes.len()), end.min(graphemes.len()));
+        if start == end && text.is_empty() { return; }
+
+        // Runs of typing or deleting undo together, broken at each space.
+        if kind == EditKind::Other || self.4.last != Some(kind) || text.chars().any(char::is_whitespace) {
+            let snapshot = (self.1.0.spans[0].clone(), self.cursor());
+            self.4.undo.push(snapshot);
+            if self.4.undo.len() > Editing::HISTORY { self.4.undo.remove(0); }
+        }
+        self.4.redo.clear();
+        self.4.last = Some(kind);
+
+        let edited = [graphemes[..start].concat(), text.to_string(), graphemes[end..].concat()].concat();
+        // A combining mark typed after a letter joins its grapheme rather than adding one.
+        let cursor = (edited.graphemes(true).count() + end).saturating_sub(graphemes.len());
+        self.1.0.spans[0] = edited;
+        self.set_cursor(cursor);
+        self.4.anchor = None;
+
+        if let Some(mask) = &mut self.1.0.mask {
+            match text.graphemes(true).count() {
+                1 => mask.typed(cursor.saturating_sub(1)),
+                _ => mask.revealed = None,
+            }
+        }
+    }
+
+    fn insert(&mut self, text: &str, kind: EditKind) {
+        let cursor = self.cursor();
+        let range = self.selection().unwrap_or((cursor, cursor));
+        self.replace(range, text, kind);
+    }
+
+    fn edit(&mut self, ctx: &mut Context, edit: &EditEvent) {
+        match edit {
+            EditEvent::SelectAll => self.select_all(),
+            EditEvent::Copy | EditEvent::Cut if self.is_secure() => {},
+            EditEvent::Copy => if let Some(text) = self.selected() { ctx.set_clipboard(text); },
+            EditEvent::Cut => if let Some(text) = self.selected() {
+                ctx.set_clipboard(text);
+                self.insert("", EditKind::Other);
+            },
+            EditEvent::Paste => {
+                let text = ctx.get_clipboard();
+                self.insert(&text, EditKind::Other);
+            },
+            EditEvent::Undo => self.undo(),
+            EditEvent::Redo => self.redo(),
+        }
+    }
+
+    fn key(&mut self, ctx: &mut Context, key: &Key, modifiers: &Modifiers) {
+        let command = modifiers.control_key() || modifiers.super_key();
+        let text = self.1.0.spans[0].clone();
+        let (shift, graphemes) = (modifiers.shift_key(), text.graphemes(true).collect::<Vec<_>>());
+        let cursor = self.cursor();
+
+        match key {
+            Key::Character(c) if command => {
+                let edit = match c.to_lowercase().as_str() {
+                    "a" => EditEvent::SelectAll,
+                    "c" => EditEvent::Copy,
+                    "x" => EditEvent::Cut,
+                    "v" => EditEvent::Paste,
+                    "z" if shift => EditEvent::Redo,
+                    "z" => EditEvent::Undo,
+                    "y" => EditEvent::Redo,
+                    _ => return,
+                };
+                self.edit(ctx, &edit);
+            },
+            Key::Character(c) => self.insert(c, EditKind::Type),
+            Key::Named(NamedKey::Enter) => self.insert("\n", EditKind::Type),
+            Key::Named(NamedKey::Space) => self.insert(" ", EditKind::Type),
+            // The on-screen keyboard's backspace sends Delete, so both erase backwards.
+            Key::Named(NamedKey::Delete | NamedKey::Backspace) => {
+                let range = match self.selection() {
+                    Some(range) => range,
+                    None if command => (word_boundary(&graphemes, cursor, false), cursor),
+                    None => (cursor.saturating_sub(1), cursor),
+                };
+                self.replace(range, "", EditKind::Delete);
+            },
+            Key::Named(NamedKey::ArrowLeft) => match self.selection() {
+                Some((start, _)) if !shift => self.move_to(start, false),
+                _ if command => self.move_to(word_boundary(&graphemes, cursor, false), shift),
+                _ => self.move_to(cursor.saturating_sub(1), shift),
+            },
+            Key::Named(NamedKey::ArrowRight) => match self.selection() {
+                Some((_, end)) if !shift => self.move_to(end, false),
+                _ if command => self.move_to(word_boundary(&graphemes, cursor, true), shift),
+                _ => self.move_to((cursor + 1).min(graphemes.len()), shift),
+            },
+            Key::Named(NamedKey::Home) => {
+                let start = graphemes[..cursor].iter().rposition(|g| is_newline(g)).map(|i| i + 1).unwrap_or(0);
+                self.move_to(start, shift);
+            },
+            Key::Named(NamedKey::End) => {
+                let end = graphemes[cursor..].iter().position(|g| is_newline(g)).map(|i| cursor + i).unwrap_or(graphemes.len());
+                self.move_to(end, shift);
+            },
+            _ => {}
+        }
+    }
 }
 
 impl OnEvent for TextEditor {
-    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
         if event.downcast_ref::<TickEvent>().is_some() && self.1.0.inner.cursor.is_some() {
+            if let Some(mask) = &mut self.1.0.mask { mask.tick(); }
+            let cursor = self.cursor();
+            self.set_cursor(cursor);
             let cursor_pos = self.1.0.inner.cursor_position();
             *self.2.x_offset() = Offset::Static(cursor_pos.0);
             *self.2.y_offset() = Offset::Static(cursor_pos.1+2.0);
-        } else if let Some(event) = event.downcast_ref::<MouseEvent>() && let Some(pos) = event.position && event.state == MouseState::Pressed {
-            self.1.0.inner.cursor_click(pos.0, pos.1) 
-        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key, ..}) = event.downcast_ref() {
-            let index = self.1.0.inner.cursor.unwrap();
-            
-            let character = match key {
-                Key::Character(c) => Some(c.chars().next().unwrap_or_default()),
-                Key::Named(NamedKey::Enter) => Some('\n'),
-                Key::Named(NamedKey::Space) => Some(' '),
-                Key::Named(NamedKey::Delete | NamedKey::Backspace) => None,
-                _ => {return vec![event];}
-            };
-
-            match character {
-                Some(c) => {
-                    match index >= self.1.0.spans[0].len() {
-                        true => self.1.0.spans[0].push(c),
-                        false => self.1.0.spans[0].insert(index, c),
-                    };
-                    if let Some(c) = self.1.0.inner.cursor.as_mut() {*c += 1;}
-                }
-                None => {
-                    self.1.0.spans[0] = {
-                        let mut chars: Vec<char> = self.1.0.spans[0].chars().collect();
-
-                        match chars.len() {
-                            1 => chars.clear(),
-                            _ if index >= chars.len() => {chars.pop();},
-                            _ => {chars.remove(index);}
-                        }
-
-                        chars.into_iter().collect()
-                    };
-                    if let Some(c) = self.1.0.inner.cursor.as_mut() { *c = c.saturating_sub(1); }
-                }
-            }
 
-            // (self.3)(ctx, &mut self.1.0.spans[0])
+            let selection = self.selection();
+            let ends = selection.map(|(start, end)| {
+                let (start, end) = (self.caret(start), self.caret(end));
+                let position = |text: &mut BasicText, i: usize| { text.cursor = Some(i); text.cursor_position() };
+                let ends = (position(&mut self.1.0.inner, start), position(&mut self.1.0.inner, end));
+                self.1.0.inner.cursor = Some(self.caret(cursor));
+                ends
+            });
+            let (width, line) = (self.1.0.inner.size().0, self.1.0.inner.spans[0].line_height.unwrap_or_default());
+            self.3.show(ends, width, line);
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() && let Some(pos) = event.position && event.state == MouseState::Pressed {
+            self.4.anchor = None;
+            self.1.0.inner.cursor_click(pos.0, pos.1);
+            self.sync_cursor();
+        } else if let Some(edit) = event.downcast_ref::<EditEvent>() && self.4.focused {
+            self.edit(ctx, edit);
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key, modifiers}) = event.downcast_ref::<KeyboardEvent>() {
+            self.key(ctx, key, modifiers);
         }
         
         vec![event]
     }
 }
 
+/// Selection and undo history of a [`TextEditor`].
+#[derive(Debug, Clone, Default)]
+struct Editing {
+    /// The cursor as a grapheme index.
+    cursor: usize,
+    /// Where the selection started; the cursor is its other end.
+    anchor: Option<usize>,
+    undo: Vec<(String, usize)>,
+    redo: Vec<(String, usize)>,
+    last: Option<EditKind>,
+    focused: bool,
+}
+
+impl Editing {
+    const HISTORY: usize = 100;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EditKind {Type, Delete, Other}
+
+/// The start of the word before `from`, or the end of the word after it.
+fn word_boundary(graphemes: &[&str], from: usize, forward: bool) -> usize {
+    let is_word = |g: &str| g.chars().next().is_some_and(char::is_alphanumeric);
+    let mut i = from.min(graphemes.len());
+    match forward {
+        true => {
+            while i < graphemes.len() && !is_word(graphemes[i]) { i += 1; }
+            while i < graphemes.len() && is_word(graphemes[i]) { i += 1; }
+        },
+        false => {
+            while i > 0 && !is_word(graphemes[i - 1]) { i -= 1; }
+            while i > 0 && is_word(graphemes[i - 1]) { i -= 1; }
+        }
+    }
+    i
+}
+
+fn is_newline(grapheme: &str) -> bool {grapheme == "\n" || grapheme == "\r\n"}
+
+/// Clipboard and history actions for the focused [`TextEditor`], e.g. from the mobile keyboard.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EditEvent {
+    SelectAll,
+    Copy,
+    Cut,
+    Paste,
+    Undo,
+    Redo,
+}
+
+impl Event for EditEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: &[Area]) -> Vec<Option<Box<dyn Event>>> {
+        children.iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+/// Tints the selected text: the rest of the first line, the full lines between and the start of the last.
 #[derive(Component, Debug, Clone)]
-pub struct TextCursor(Stack, Opt<Rectangle>);
+struct Highlight(Stack, Vec<Bin<Stack, Rectangle>>, #[skip] Color);
 
-impl OnEvent for TextCursor {}
+impl OnEvent for Highlight {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.2 = Highlight::color(theme);
+            self.1.clear();
+        }
+        vec![event]
+    }
+}
+
+impl Highlight {
+    fn new(theme: &Theme) -> Self {
+        Highlight(Stack::start(), vec![], Highlight::color(theme))
+    }
+
+    fn color(theme: &Theme) -> Color {
+        let brand = theme.colors().get(ptsd::Brand);
+        Color(brand.0, brand.1, brand.2, 80)
+    }
+
+    /// Covers the text between the `ends` of the selection, given as cursor positions.
+    fn show(&mut self, ends: Option<((f32, f32), (f32, f32))>, width: f32, line: f32) {
+        let rect = |color: Color, x: f32, y: f32, w: f32, h: f32| Bin(
+            Stack(Offset::Static(x), Offset::Static(y), Size::Static(w.max(0.0)), Size::Static(h.max(0.0)), Padding::default()),
+            Rectangle::new(color, 0.0, None)
+        );
+
+        self.1 = match ends {
+            None => vec![],
+            Some(((x1, y1), (x2, y2))) if y1 == y2 => vec![rect(self.2, x1, y1, x2 - x1, line)],
+            Some(((x1, y1), (x2, y2))) => vec![
+                rect(self.2, x1, y1, width - x1, line),
+                rect(self.2, 0.0, y1 + line, width, y2 - y1 - line),
+                rect(self.2, 0.0, y2, x2, line),
+            ],
+        };
+    }
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct TextCursor(Stack, Opt<Rectangle>, #[skip] TextStyle, #[skip] TextSize);
+
+impl OnEvent for TextCursor {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let (color, _) = self.2.get(theme);
+            self.0.3 = Size::Static(theme.font_size(self.3));
+            *self.1.inner().background() = color.into();
+        }
+        vec![event]
+    }
+}
 
 impl TextCursor {
-    pub fn new(theme: &Theme, style: TextStyle, size: TextSize) -> Self {
+    pub fn new(theme: &Theme, style: TextStyle, text_size: TextSize) -> Self {
         let (color, _) = style.get(theme);
-        let size = theme.fonts().get_size(size);
+        let size = theme.font_size(text_size);
         TextCursor(
             Stack(Offset::Start, Offset::End, Size::Static(2.0), Size::Static(size), Padding::default()), 
-            Opt::new(Rectangle::new(color, 0.0, None), true)
+            Opt::new(Rectangle::new(color, 0.0, None), true),
+            style,
+            text_size
         )
     }
 
@@ -208,42 +586,254 @@ impl TextCursor {
     pub fn y_offset(&mut self) -> &mut Offset { &mut self.0.1 }
 }
 
-// // /// # Bulleted Text
-// // ///
-// // /// A component for rendering lists with bullet points.
-// // ///
-// // /// <img src="https://raw.githubusercontent.com/ramp-stack/pelican_ui_std/main/src/examples/bulleted_text.png"
-// // ///      alt="Bulleted Text Example"
-// // ///      width="400">
-// // ///
-// // /// ## Example
-// // ///```rust
-// // /// let text_size = ctx.theme.fonts.size.md;
-// // /// let items = vec!["Feed the chairs at midnight.", "Borrow a broom from the moon.", "Vacuum the car inside out.", "Hide the clock beneath the carpet."];
-// // /// let list = BulletedText::new(ctx, items, TextStyle::Primary, text_size);
-// // ///```
-// // #[derive(Debug, Component)]
-// // pub struct BulletedText(Column, Vec<BulletedTextContent>);
-
-// // impl OnEvent for BulletedText {}
-
-// // impl BulletedText {
-// //     pub fn new(ctx: &mut Context, text: Vec<&str>, style: TextStyle, size: f32) -> Self {
-// //         let color = style.get(ctx).0;
-// //         let items = text.into_iter().map(|t| BulletedTextContent::new(ctx, t, color, style, size)).collect();
-// //         BulletedText(Column::center(8.0), items)
-// //     }
-// // }
-
-// // #[derive(Debug, Component)]
-// // struct BulletedTextContent(Row, Shape, ExpandableText);
-// // impl OnEvent for BulletedTextContent {}
-// // impl BulletedTextContent {
-// //     fn new(ctx: &mut Context, text: &str, color: Color, style: TextStyle, size: f32) -> Self {
-// //         BulletedTextContent(
-// //             Row::new(size*0.75, Offset::Center, Size::Fit, Padding::default()), // change this offset to be line_height - circle size / 2
-// //             Circle::new(size*0.2, color, false),
-// //             ExpandableText::new(ctx, text, style, size, Align::Left, None)
-// //         )
-// //     }
-// // }
\ No newline at end of file
+/// ## Bulleted Text
+///
+/// A list with a bullet before each item. Any item can hold a nested
+/// [`BulletedText`] or [`NumberedText`], inset by the list's indentation.
+///
+/// <img src="https://raw.githubusercontent.com/ramp-stack/pelican_ui_std/main/src/examples/bulleted_text.png"
+///      alt="Bulleted Text Example"
+///      width="400">
+///
+/// ### Example
+/// ```rust
+/// let items = vec!["Feed the chairs at midnight.", "Borrow a broom from the moon.", "Vacuum the car inside out."];
+/// let steps = NumberedText::new(theme, vec!["Find the moon.", "Ask nicely."], TextSize::Md, TextStyle::Primary);
+/// let list = BulletedText::new(theme, items, TextSize::Md, TextStyle::Primary).nested(1, steps);
+/// ```
+#[derive(Debug, Component, Clone)]
+pub struct BulletedText(Column, Vec<ListEntry>, #[skip] f32);
+impl OnEvent for BulletedText {}
+
+impl BulletedText {
+    pub fn new(theme: &Theme, items: Vec<&str>, size: TextSize, style: TextStyle) -> Self {
+        let items = items.into_iter().map(|t| ListEntry::new(theme, ListMarker::bullet(theme, size, style), t, size, style)).collect();
+        BulletedText(Column::new(8.0, Offset::Start, Size::Fill, Padding::default(), None), items, 24.0)
+    }
+
+    /// Items with inline Markdown, rendered as [`RichText`] readable on `surface`.
+    pub fn rich(theme: &Theme, items: Vec<&str>, size: TextSize, style: TextStyle, surface: Surface) -> Self {
+        let items = items.into_iter().map(|t| ListEntry::rich(theme, ListMarker::bullet(theme, size, style), t, size, style, surface)).collect();
+        BulletedText(Column::new(8.0, Offset::Start, Size::Fill, Padding::default(), None), items, 24.0)
+    }
+
+    /// The width of the widest item as laid out, nested lists included.
+    pub fn width(&self) -> f32 {self.1.iter().map(ListEntry::width).fold(0.0, f32::max)}
+
+    /// Places `list` under the item at `index`.
+    pub fn nested(mut self, index: usize, list: impl Into<NestedList>) -> Self {
+        if let Some(entry) = self.1.get_mut(index) { entry.nest(list.into(), self.2); }
+        self
+    }
+
+    /// How far nested lists are inset. Defaults to 24.0.
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.2 = indent;
+        self.1.iter_mut().for_each(|entry| entry.indent(indent));
+        self
+    }
+}
+
+/// ## Numbered Text
+///
+/// A list numbered from 1, otherwise the same as [`BulletedText`].
+///
+/// ### Example
+/// ```rust
+/// let terms = NumberedText::new(theme, vec!["Be kind.", "Keep your keys safe."], TextSize::Md, TextStyle::Primary)
+///     .nested(1, BulletedText::new(theme, vec!["Write down your recovery phrase."], TextSize::Md, TextStyle::Secondary))
+///     .indent(32.0);
+/// ```
+#[derive(Debug, Component, Clone)]
+pub struct NumberedText(Column, Vec<ListEntry>, #[skip] f32);
+impl OnEvent for NumberedText {}
+
+impl NumberedText {
+    pub fn new(theme: &Theme, items: Vec<&str>, size: TextSize, style: TextStyle) -> Self {
+        let width = items.len().to_string().len();
+        let items = items.into_iter().enumerate().map(|(i, t)| {
+            ListEntry::new(theme, ListMarker::number(theme, i + 1, width, size, style), t, size, style)
+        }).collect();
+        NumberedText(Column::new(8.0, Offset::Start, Size::Fill, Padding::default(), None), items, 24.0)
+    }
+
+    /// The width of the widest item as laid out, nested lists included.
+    pub fn width(&self) -> f32 {self.1.iter().map(ListEntry::width).fold(0.0, f32::max)}
+
+    /// Places `list` under the item at `index`.
+    pub fn nested(mut self, index: usize, list: impl Into<NestedList>) -> Self {
+        if let Some(entry) = self.1.get_mut(index) { entry.nest(list.into(), self.2); }
+        self
+    }
+
+    /// How far nested lists are inset. Defaults to 24.0.
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.2 = indent;
+        self.1.iter_mut().for_each(|entry| entry.indent(indent));
+        self
+    }
+}
+
+/// A list placed under an item of a [`BulletedText`] or [`NumberedText`].
+#[derive(Debug, Component, Clone)]
+pub enum NestedList {
+    Bulleted {layout: Stack, list: BulletedText},
+    Numbered {layout: Stack, list: NumberedText},
+}
+impl OnEvent for NestedList {}
+
+impl From<BulletedText> for NestedList {
+    fn from(list: BulletedText) -> Self {NestedList::Bulleted {layout: Stack::default(), list}}
+}
+
+impl From<NumberedText> for NestedList {
+    fn from(list: NumberedText) -> Self {NestedList::Numbered {layout: Stack::default(), list}}
+}
+
+/// An item's line and, optionally, its nested list inset from the leading edge.
+#[derive(Debug, Component, Clone)]
+struct ListEntry(Column, ListLine, Option<Bin<Stack, NestedList>>, #[skip] bool);
+impl OnEvent for ListEntry {}
+
+impl ListEntry {
+    fn new(theme: &Theme, marker: ListMarker, text: &str, size: TextSize, style: TextStyle) -> Self {
+        let text = ExpandableText::new(theme, text, size, style, theme.locale().start(), None);
+        ListEntry::line(theme, marker, Some(text), None)
+    }
+
+    fn rich(theme: &Theme, marker: ListMarker, text: &str, size: TextSize, style: TextStyle, surface: Surface) -> Self {
+        let text = RichText::new(theme, text, size, style, theme.locale().start(), None).on(theme, surface);
+        ListEntry::line(theme, marker, None, Some(text))
+    }
+
+    fn line(theme: &Theme, marker: ListMarker, plain: Option<ExpandableText>, rich: Option<RichText>) -> Self {
+        let locale = theme.locale();
+        let row = Row::new(8.0, Offset::Start, Size::Fit, Padding::default());
+        let line = ListLine(Directional::new(row, locale), marker, plain, rich);
+        ListEntry(Column::new(8.0, Offset::Start, Size::Fill, Padding::default(), None), line, None, locale.is_rtl())
+    }
+
+    fn width(&self) -> f32 {
+        let ListLine(_, marker, plain, rich) = &self.1;
+        let marker = match marker.0.2 { Size::Static(width) => width, _ => 0.0 };
+        let text = plain.as_ref().map(|t| t.0.inner().size().0).or(rich.as_ref().map(|t| t.inner().size().0)).unwrap_or_default();
+        let nested = self.2.as_ref().map(|Bin(layout, list)| {
+            let Padding(left, _, right, _) = layout.4;
+            left + right + match list {
+                NestedList::Bulleted {list, ..} => list.width(),
+                NestedList::Numbered {list, ..} => list.width(),
+            }
+        }).unwrap_or_default();
+        (marker + 8.0 + text).max(nested)
+    }
+
+    fn nest(&mut self, list: NestedList, indent: f32) {
+        self.2 = Some(Bin(self.inset(indent), list));
+    }
+
+    fn indent(&mut self, indent: f32) {
+        let inset = self.inset(indent);
+        if let Some(nested) = &mut self.2 { nested.0 = inset; }
+    }
+
+    fn inset(&self, indent: f32) -> Stack {
+        let padding = match self.3 {
+            true => Padding(0.0, 0.0, indent, 0.0),
+            false => Padding(indent, 0.0, 0.0, 0.0),
+        };
+        Stack(Offset::Start, Offset::Start, Size::Fill, Size::Fit, padding)
+    }
+}
+
+#[derive(Debug, Component, Clone)]
+struct ListLine(Directional, ListMarker, Option<ExpandableText>, Option<RichText>);
+impl OnEvent for ListLine {}
+
+/// A bullet centered on the first line of its item, or a number such as "2.".
+#[derive(Debug, Component, Clone)]
+struct ListMarker(Stack, Option<Shape>, Option<Text>, #[skip] TextStyle);
+
+impl OnEvent for ListMarker {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() && let Some(bullet) = &mut self.1 {
+            bullet.color = self.3.get(theme).0.into();
+        }
+        vec![event]
+    }
+}
+
+impl ListMarker {
+    fn bullet(theme: &Theme, size: TextSize, style: TextStyle) -> Self {
+        let size = theme.font_size(size);
+        let layout = Stack(Offset::Center, Offset::Center, Size::Static(size*0.75), Size::Static(size*1.25), Padding::default());
+        ListMarker(layout, Some(Circle::new(size*0.3, style.get(theme).0, false)), None, style)
+    }
+
+    /// `digits` is the widest number's length, so every item's text starts at the same place.
+    fn number(theme: &Theme, number: usize, digits: usize, text_size: TextSize, style: TextStyle) -> Self {
+        let size = theme.font_size(text_size);
+        let x = if theme.locale().is_rtl() { Offset::Start } else { Offset::End };
+        let layout = Stack(x, Offset::Start, Size::Static(size*(digits as f32*0.6 + 0.4)), Size::Fit, Padding::default());
+        let number = Text::new(theme, &format!("{number}."), text_size, style, theme.locale().end(), None);
+        ListMarker(layout, None, Some(number), style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_one_symbol_per_grapheme() {
+        let mask = Mask::new(false);
+        assert_eq!(mask.apply("hunter2"), "•••••••");
+        assert_eq!(mask.apply("e\u{301}🇳🇱"), "••");
+        assert_eq!(mask.apply(""), "");
+    }
+
+    #[test]
+    fn reveals_the_last_typed_grapheme_for_a_while() {
+        let mut mask = Mask::new(true);
+        mask.typed(1);
+        assert_eq!(mask.apply("pa\u{301}ss"), "•a\u{301}••");
+
+        (0..Mask::REVEAL_TICKS).for_each(|_| mask.tick());
+        assert_eq!(mask.apply("pa\u{301}ss"), "••••");
+    }
+
+    fn graphemes(text: &str) -> Vec<&str> {text.graphemes(true).collect()}
+
+    #[test]
+    fn word_boundaries_skip_spaces_then_the_word() {
+        let text = graphemes("hello,  big world");
+        assert_eq!(word_boundary(&text, 0, true), 5);
+        assert_eq!(word_boundary(&text, 5, true), 11);
+        assert_eq!(word_boundary(&text, text.len(), false), 12);
+        assert_eq!(word_boundary(&text, 12, false), 8);
+        assert_eq!(word_boundary(&text, 3, false), 0);
+    }
+
+    #[test]
+    fn word_boundaries_stop_at_the_ends() {
+        let text = graphemes("  word  ");
+        assert_eq!(word_boundary(&text, 8, true), 8);
+        assert_eq!(word_boundary(&text, 6, true), 8);
+        assert_eq!(word_boundary(&text, 2, false), 0);
+        assert_eq!(word_boundary(&text, 99, false), 2);
+    }
+
+    #[test]
+    fn word_boundaries_count_graphemes() {
+        let text = graphemes("cafe\u{301} ole\u{301}");
+        assert_eq!(text.len(), 8);
+        assert_eq!(word_boundary(&text, 0, true), 4);
+        assert_eq!(word_boundary(&text, 8, false), 5);
+    }
+
+    #[test]
+    fn hidden_masks_never_reveal() {
+        let mut mask = Mask::new(false);
+        mask.typed(0);
+        assert_eq!(mask.apply("abc"), "•••");
+    }
+}
@@ -0,0 +1,229 @@
+use prism::event::{self, OnEvent, Event, TickEvent, MouseEvent, MouseState, KeyboardEvent};
+use prism::drawable::{Component, SizedTree};
+use prism::Context;
+use prism::layout::{Column, Row, Stack, Offset, Size, Padding, ScrollAnchor};
+use prism::display::{Opt, Bin, EitherOr};
+
+use ptsd::interfaces::ShowKeyboard;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::theme::{Theme, Color, ThemeChanged};
+use crate::components::text::{Text, TextSize, TextStyle, TextEditor, ExpandableText};
+use crate::components::Rectangle;
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::{FocusEvent, FocusState};
+use crate::interface::system::{InputType, KeyboardType};
+
+/// ## Text Area
+///
+/// A multi-line text field. It grows with its text between a minimum and maximum
+/// number of lines and scrolls beyond that. With a character limit it shows a
+/// counter such as "120/500", which turns red once the text is too long.
+///
+/// ### Example
+/// ```rust
+/// let bio = TextArea::new(theme, None, Some("Bio"), Some("Tell people about yourself"), None)
+///     .lines(3, 8)
+///     .limit(500);
+///
+/// let fits = bio.is_valid();
+/// ```
+#[derive(Debug, Component, Clone)]
+pub struct TextArea {
+    layout: Column,
+    label: Option<Text>,
+    field: AreaField,
+    footer: AreaFooter,
+    #[skip] pub error: Option<String>,
+    #[skip] value: String,
+    #[skip] lines: (usize, usize),
+    #[skip] limit: Option<usize>,
+    #[skip] counted: Option<usize>,
+    #[skip] focus: FocusState,
+    #[skip] is_focused: bool,
+    #[skip] theme: Theme,
+}
+
+impl Accessible for TextArea {
+    fn accessibility(&self) -> AccessNode {
+        let node = AccessNode::new(Role::TextInput).value(&self.value).focused(self.is_focused).error(self.error.clone());
+        match &self.label {
+            Some(label) => node.label(label.spans[0].clone()),
+            None => node,
+        }
+    }
+}
+
+impl TextArea {
+    pub fn new(theme: &Theme, value: Option<&str>, label: Option<&str>, placeholder: Option<&str>, help_text: Option<&str>) -> Self {
+        let value = value.unwrap_or_default();
+        let placeholder = placeholder.map(str::to_string).unwrap_or_else(|| theme.tr("text-input-placeholder"));
+        let lines = (3, 8);
+
+        TextArea {
+            layout: Column::new(16.0, Offset::Start, Size::Fill, Padding::default(), None),
+            label: label.map(|l| Text::new(theme, l, TextSize::H5, TextStyle::Heading, theme.locale().start(), None)),
+            field: AreaField::new(theme, value, &placeholder, lines),
+            footer: AreaFooter::new(theme, help_text.filter(|h| !h.is_empty())),
+            error: None,
+            value: value.to_string(),
+            lines,
+            limit: None,
+            counted: None,
+            focus: FocusState::default(),
+            is_focused: false,
+            theme: theme.clone(),
+        }
+    }
+
+    /// Grows from `min` to `max` lines of text before scrolling. Defaults to 3 and 8.
+    pub fn lines(mut self, min: usize, max: usize) -> Self {
+        self.lines = (min.max(1), max.max(min).max(1));
+        let placeholder = self.field.2.1.2.inner().0.spans[0].clone();
+        self.field = AreaField::new(&self.theme, &self.value, &placeholder, self.lines);
+        self
+    }
+
+    /// Shows a character counter; more than `max` characters makes the area invalid.
+    pub fn limit(mut self, max: usize) -> Self {
+        self.limit = Some(max);
+        self.counted = None;
+        self
+    }
+
+    pub fn value(&self) -> String {self.value.clone()}
+
+    /// Whether the text fits within the character limit, counted in graphemes
+    /// so an emoji or an accented letter counts once.
+    pub fn is_valid(&self) -> bool {
+        self.limit.is_none_or(|limit| self.length() <= limit)
+    }
+
+    fn length(&self) -> usize {self.value.graphemes(true).count()}
+
+    pub fn error(&mut self, error: Result<(), String>) {
+        match error {
+            Ok(_) => self.error = None,
+            Err(e) => self.error = (!e.is_empty()).then_some(e),
+        }
+    }
+
+    fn focused(&mut self, ctx: &mut Context, focused: bool) {
+        if focused && !self.is_focused {
+            ctx.emit(ShowKeyboard(true));
+            ctx.emit(KeyboardType(InputType::Text));
+        }
+        self.is_focused = focused;
+    }
+
+    fn rebuild(&mut self, theme: &Theme) {
+        let label = self.label.as_ref().map(|l| l.spans[0].clone());
+        let help = self.footer.1.left().as_ref().map(|h| h.0.spans[0].clone());
+        let placeholder = self.field.2.1.2.inner().0.spans[0].clone();
+        let (min, max) = self.lines;
+
+        let mut area = TextArea::new(theme, Some(&self.value), label.as_deref(), Some(&placeholder), help.as_deref()).lines(min, max);
+        area.limit = self.limit;
+        area.error = self.error.take();
+        area.focus = self.focus;
+        area.is_focused = self.is_focused;
+        *self = area;
+    }
+}
+
+impl OnEvent for TextArea {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.rebuild(theme);
+        } else if let Some(focus) = event.downcast_ref::<FocusEvent>() && self.focus.handle(focus) {
+            let focused = self.focus.is_focused();
+            self.focused(ctx, focused);
+        } else if let Some(MouseEvent{state: MouseState::Pressed, position, ..}) = event.downcast_ref::<MouseEvent>() {
+            self.focused(ctx, position.is_some());
+        } else if let Some(MouseEvent{state: MouseState::Scroll(_, y), position: Some(_), ..}) = event.downcast_ref::<MouseEvent>() {
+            self.field.2.1.0.adjust_scroll(*y);
+        } else if let Some(event::TextInput::Focused(false)) = event.downcast_ref::<event::TextInput>() {
+            self.is_focused = false;
+        } else if event.downcast_ref::<KeyboardEvent>().is_some() && !self.is_focused {
+            // Only the focused area types.
+            return vec![];
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            let lines = &mut self.field.2.1;
+            self.value = lines.1.inner().1.0.spans[0].clone();
+            lines.1.display(self.is_focused || !self.value.is_empty());
+            lines.2.display(!self.is_focused && self.value.is_empty());
+            lines.1.inner().display_cursor(self.is_focused);
+
+            let colors = self.theme.colors();
+            let outline = match (&self.error, self.is_focused) {
+                (Some(_), _) => colors.get(ptsd::Status::Danger),
+                (None, true) => colors.get(ptsd::Outline::Primary),
+                (None, false) => colors.get(ptsd::Outline::Secondary),
+            };
+            if let Some(color) = self.field.1.outline() { *color = outline.into(); }
+
+            self.footer.1.display_left(self.error.is_none());
+            if let Some(e) = &self.error { self.footer.1.right().0.spans[0] = e.to_string(); }
+
+            let count = self.length();
+            if let Some(limit) = self.limit && self.counted != Some(count) {
+                let style = if count > limit { TextStyle::Error } else { TextStyle::Secondary };
+                self.footer.2 = Some(Text::new(&self.theme, &format!("{count}/{limit}"), TextSize::Sm, style, self.theme.locale().end(), None));
+                self.counted = Some(count);
+            }
+        }
+        vec![event]
+    }
+}
+
+/// The outlined box; its height follows the text between the line bounds.
+#[derive(Debug, Component, Clone)]
+struct AreaField(Stack, Rectangle, Bin<Stack, AreaLines>);
+impl OnEvent for AreaField {}
+
+impl AreaField {
+    fn new(theme: &Theme, value: &str, placeholder: &str, (min, max): (usize, usize)) -> Self {
+        let padding = 24.0;
+        let line = theme.font_size(TextSize::Md) * 1.25;
+        let (min, max) = (min as f32 * line + padding, max as f32 * line + padding);
+        let height = Size::custom(move |heights: Vec<(f32, f32)>| {
+            let height = heights[1].1.clamp(min, max);
+            (height, height)
+        });
+
+        let outline = theme.colors().get(ptsd::Outline::Secondary);
+        AreaField(
+            Stack(Offset::Start, Offset::Start, Size::Fill, height, Padding::default()),
+            Rectangle::new(Color::TRANSPARENT, 8.0, Some((1.0, outline))),
+            Bin(Stack(Offset::Start, Offset::Start, Size::Fill, Size::Fit, Padding(16.0, 12.0, 16.0, 12.0)), AreaLines::new(theme, value, placeholder)),
+        )
+    }
+}
+
+/// The editor or, while empty and unfocused, the placeholder; scrolls once the box stops growing.
+#[derive(Debug, Component, Clone)]
+struct AreaLines(Column, Opt<TextEditor>, Opt<ExpandableText>);
+impl OnEvent for AreaLines {}
+
+impl AreaLines {
+    fn new(theme: &Theme, value: &str, placeholder: &str) -> Self {
+        let editor = TextEditor::new(theme, value, TextSize::Md, TextStyle::Primary, theme.locale().start());
+        let placeholder = ExpandableText::new(theme, placeholder, TextSize::Md, TextStyle::Secondary, theme.locale().start(), None);
+        let layout = Column::new(0.0, Offset::Start, Size::Fill, Padding::default(), Some(ScrollAnchor::End));
+        AreaLines(layout, Opt::new(editor, !value.is_empty()), Opt::new(placeholder, value.is_empty()))
+    }
+}
+
+/// Help text or the error on one side, the character counter on the other.
+#[derive(Debug, Component, Clone)]
+struct AreaFooter(Row, EitherOr<Option<ExpandableText>, ExpandableText>, Option<Text>);
+impl OnEvent for AreaFooter {}
+
+impl AreaFooter {
+    fn new(theme: &Theme, help_text: Option<&str>) -> Self {
+        let help = help_text.map(|t| ExpandableText::new(theme, t, TextSize::Sm, TextStyle::Secondary, theme.locale().start(), None));
+        let error = ExpandableText::new(theme, "", TextSize::Sm, TextStyle::Error, theme.locale().start(), None);
+        AreaFooter(Row::new(8.0, Offset::Start, Size::Fit, Padding::default()), EitherOr::new(help, error), None)
+    }
+}
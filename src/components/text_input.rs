@@ -8,17 +8,23 @@ use prism::display::{EitherOr, Opt, Bin};
 use ptsd::interactions;
 
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::theme::{Theme, Color, Icons};
+use crate::theme::{Theme, Color, Icons, ThemeChanged};
 
-use crate::components::text::{Text, TextSize, TextStyle, TextEditor, ExpandableText};
+use crate::components::text::{Text, TextSize, TextStyle, TextEditor, ExpandableText, Mask};
 use crate::components::Rectangle;
-use crate::components::button::SecondaryIconButton;
+use crate::components::button::{SecondaryIconButton, GhostIconButton};
 use crate::components::QRCodeScannedEvent;
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::{FocusEvent, FocusState};
+use crate::interface::system::{InputType, KeyboardType};
 
 /// ## Text Input
 ///
 /// A text input field with optional label, placeholder, help text, and an icon button.  
+/// Set an [`InputType`] with [`TextInput::input_type`] to get matching keys on the mobile keyboard,
+/// or use [`TextInput::secure`] for passwords.
 ///
 /// <img src="https://raw.githubusercontent.com/ramp-stack/pelican_ui_std/main/src/examples/text_input.png"
 ///      alt="Text Input Example"
@@ -42,6 +48,22 @@ pub struct TextInput {
     pub inner: interactions::InputField,
     hint: EitherOr<Option<ExpandableText>, ExpandableText>,
     #[skip] pub error: Option<String>,
+    #[skip] focus: FocusState,
+}
+
+impl Accessible for TextInput {
+    fn accessibility(&self) -> AccessNode {
+        let content = self.inner.2.as_any().downcast_ref::<_InputContent>().unwrap();
+        let value = match &content.secure {
+            Some(secure) if !secure.shown.load(Ordering::Relaxed) => Mask::new(false).apply(&content.value),
+            _ => content.value.clone(),
+        };
+        let node = AccessNode::new(Role::TextInput).value(value).focused(content.is_focused || self.focus.is_focused()).error(self.error.clone());
+        match self.label() {
+            Some(label) => node.label(label),
+            None => node,
+        }
+    }
 }
 
 type InputCallback = Arc<Mutex<dyn FnMut(&mut Context, &mut String) + 'static>>;
@@ -76,7 +98,8 @@ impl TextInput {
             label: label.map(|l| Text::new(theme, l, TextSize::H5, TextStyle::Heading, Align::Left, None)),
             inner: input_field, 
             hint: EitherOr::new(help, error),
-            error: None
+            error: None,
+            focus: FocusState::default(),
         }
     }
     
@@ -84,6 +107,31 @@ impl TextInput {
         Self::new(theme, None, Some("First name"), None, None, None)
     }
 
+    pub fn input_type(mut self, input_type: InputType) -> Self {
+        self.inner.2.as_any_mut().downcast_mut::<_InputContent>().unwrap().input_type = input_type;
+        self
+    }
+
+    /// Masks the value for password entry and adds a button to show or hide it.
+    /// With `reveal_last`, each typed character is shown for a moment before it's masked.
+    pub fn secure(mut self, theme: &Theme, reveal_last: bool) -> Self {
+        let content = self.inner.2.as_any_mut().downcast_mut::<_InputContent>().unwrap();
+        let shown = Arc::new(AtomicBool::new(false));
+        let toggle = |icon: Icons, show: bool| {
+            let shown = shown.clone();
+            GhostIconButton::new(theme, icon, move |_: &mut Context, _: &Theme| shown.store(show, Ordering::Relaxed))
+        };
+        content.visibility = Some(EitherOr::new(toggle(Icons::Show, true), toggle(Icons::Hide, false)));
+        content.default.inner().inner().secure(Some(Mask::new(reveal_last)));
+        content.secure = Some(Secure { shown, reveal_last });
+        content.input_type = InputType::Password;
+        self
+    }
+
+    pub fn label(&self) -> Option<String> {
+        self.label.as_ref().map(|l| l.spans[0].clone())
+    }
+
     pub fn value(&self) -> String {
         self.inner.2.as_any().downcast_ref::<_InputContent>().unwrap().value.to_string()
     } 
@@ -95,11 +143,36 @@ impl TextInput {
             Err(e) => self.error = (!e.is_empty()).then_some(e),
         }
     }
+
+    fn rebuild(&mut self, theme: &Theme) {
+        let label = self.label.as_ref().map(|l| l.spans[0].clone());
+        let help = self.hint.left().as_ref().map(|h| h.0.spans[0].clone());
+        let content = self.inner.2.as_any_mut().downcast_mut::<_InputContent>().unwrap();
+        let placeholder = content.empty.inner().inner().0.spans[0].clone();
+        let value = content.value.clone();
+        let icon_button = content.icon.zip(content.on_submit.clone());
+        let input_type = content.input_type;
+        let secure = content.secure.as_ref().map(|s| s.reveal_last);
+
+        let (error, focus) = (self.error.take(), self.focus);
+        let input = TextInput::new(theme, Some(&value), label.as_deref(), Some(&placeholder), help.as_deref(), icon_button).input_type(input_type);
+        *self = match secure {
+            Some(reveal_last) => input.secure(theme, reveal_last),
+            None => input,
+        };
+        self.error(error.map(Err).unwrap_or(Ok(())));
+        self.focus = focus;
+    }
 }
 
 impl OnEvent for TextInput { 
     fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
-        if event.as_any().downcast_ref::<TickEvent>().is_some() { 
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.rebuild(theme);
+        } else if let Some(focus) = event.downcast_ref::<FocusEvent>() && self.focus.handle(focus) {
+            // Keyboard focus places the caret in the field, as clicking it would.
+            return vec![event, Box::new(event::TextInput::Focused(self.focus.is_focused()))];
+        } else if event.as_any().downcast_ref::<TickEvent>().is_some() { 
             self.hint.display_left(self.error.is_none()); 
             if let Some(e) = &self.error && !e.is_empty() {
                 self.hint.right().0.spans[0] = e.to_string();
@@ -114,12 +187,24 @@ struct _InputContent {
     layout: Row,
     default: Opt<Bin<Stack, TextEditor>>,
     empty: Opt<Bin<Stack, ExpandableText>>,
+    visibility: Option<EitherOr<GhostIconButton, GhostIconButton>>,
     button: Option<SecondaryIconButton>,
+    #[skip] icon: Option<Icons>,
     #[skip] pub value: String,
     #[skip] on_submit: Option<InputCallback>,
     #[skip] is_focused: bool,
+    #[skip] input_type: InputType,
+    #[skip] secure: Option<Secure>,
 }
 
+/// Whether a secure field is currently showing its value.
+#[derive(Clone)]
+struct Secure {
+    shown: Arc<AtomicBool>,
+    reveal_last: bool,
+}
+
+// Deliberately opaque: the value may be a password.
 impl std::fmt::Debug for _InputContent { 
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { 
         write!(f, "_InputContent") 
@@ -133,21 +218,26 @@ impl _InputContent {
         placeholder: Option<&str>,
         button: Option<(Icons, InputCallback)>,
     ) -> Self {
+        let icon = button.as_ref().map(|(icon, _)| *icon);
         let (button, on_submit) = button.map(|(icon, cb)| {
             let btn = SecondaryIconButton::medium(theme, icon, |ctx: &mut Context, _: &Theme| ctx.emit(TextInputEvent::Submit));
             (Some(btn), Some(cb))
         }).unwrap_or((None, None));
         
         let default = TextEditor::new(theme, value.unwrap_or_default(), TextSize::Md, TextStyle::Primary, Align::Left); 
-        let empty = ExpandableText::new(theme, placeholder.unwrap_or("Enter text..."), TextSize::Md, TextStyle::Secondary, Align::Left, None);
+        let empty = ExpandableText::new(theme, &placeholder.map(str::to_string).unwrap_or_else(|| theme.tr("text-input-placeholder")), TextSize::Md, TextStyle::Secondary, Align::Left, None);
         _InputContent { 
             layout: Row::new(0.0, Offset::End, Size::Fit, Padding(16.0, 8.0, 8.0, 8.0)), 
             default: Opt::new(Bin(Stack(Offset::Start, Offset::Start, Size::Fit, Size::Fit, Padding(0.0, 8.0, 16.0, 8.0)), default), false), 
             empty: Opt::new(Bin(Stack(Offset::Start, Offset::Start, Size::Fit, Size::Fit, Padding(0.0, 8.0, 16.0, 8.0)), empty), true), 
+            visibility: None,
             button,
+            icon,
             value: value.unwrap_or_default().to_string(), 
             on_submit,
             is_focused: false,
+            input_type: InputType::Text,
+            secure: None,
         }
     }
 }
@@ -162,6 +252,7 @@ impl OnEvent for _InputContent {
             self.default.inner().inner().1.0.spans[0] = data.to_string();
         } else if let Some(event::TextInput::Focused(x)) = event.downcast_ref::<event::TextInput>() {
             self.is_focused = *x;
+            if *x { ctx.emit(KeyboardType(self.input_type)); }
             // println!("FOCUSED {:?}", self.is_focused);
         } else if event.downcast_ref::<TickEvent>().is_some() {
             // if let Some(i) = ctx.state.get::<TextInputState>() {
@@ -172,6 +263,13 @@ impl OnEvent for _InputContent {
 
             self.value = self.default.inner().inner().1.0.spans[0].clone();
 
+            if let Some(secure) = &self.secure {
+                let shown = secure.shown.load(Ordering::Relaxed);
+                let editor = self.default.inner().inner();
+                if editor.is_secure() == shown { editor.secure((!shown).then(|| Mask::new(secure.reveal_last))); }
+                if let Some(toggle) = &mut self.visibility { toggle.display_left(!shown); }
+            }
+
             self.default.display(self.is_focused);
             self.empty.display(!self.is_focused);
             self.default.inner().inner().display_cursor(self.is_focused);
@@ -0,0 +1,299 @@
+use prism::event::{OnEvent, Event, TickEvent, KeyboardEvent, KeyboardState, Key, NamedKey};
+use prism::layout::{Column, Row, Size, Offset, Padding};
+use prism::drawable::{Component, SizedTree};
+use prism::canvas::Align;
+use prism::Context;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeDelta, TimeZone, Timelike};
+
+use std::sync::{Arc, Mutex};
+
+use crate::Shared;
+use crate::theme::{Theme, Icons, ThemeChanged};
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::FocusRing;
+use crate::components::button::GhostIconButton;
+use crate::components::text::{Text, TextSize, TextStyle};
+use crate::locale::Directional;
+
+/// ## Time Picker
+///
+/// Hour and minute steppers, an AM/PM stepper on a 12-hour clock and an optional
+/// timezone selector. When focused, ArrowUp/ArrowDown change the minutes by the
+/// step interval and PageUp/PageDown change the hour.
+///
+/// Whether the clock shows 24 hours follows the theme's locale unless set with
+/// [`TimePicker::twenty_four_hour`].
+///
+/// ### Example
+/// ```rust
+/// let picker = TimePicker::new(theme, NaiveTime::from_hms_opt(9, 30, 0).unwrap(), |ctx: &mut Context, theme: &Theme, time: NaiveTime| {
+///     println!("Picked {time}");
+/// })
+/// .step(15)
+/// .timezones(theme, vec![
+///     ("UTC".to_string(), FixedOffset::east_opt(0).unwrap()),
+///     ("CET".to_string(), FixedOffset::east_opt(3600).unwrap()),
+/// ], 0);
+///
+/// let meeting = picker.value_on(NaiveDate::from_ymd_opt(2026, 3, 2).unwrap());
+/// ```
+#[derive(Debug, Component, Clone)]
+pub struct TimePicker(Column, ClockRow, Option<ZoneSelector>, FocusRing, #[skip] Arc<Mutex<Clock>>, #[skip] OnTime, #[skip] Option<(NaiveTime, usize)>, #[skip] Theme);
+
+impl OnEvent for TimePicker {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.7 = theme.clone();
+            self.6 = None;
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(key), ..}) = event.downcast_ref::<KeyboardEvent>() && self.3.is_focused() {
+            let mut clock = self.4.lock().unwrap();
+            let time = match key {
+                NamedKey::ArrowUp => clock.minutes(1),
+                NamedKey::ArrowDown => clock.minutes(-1),
+                NamedKey::PageUp => clock.hours(1),
+                NamedKey::PageDown => clock.hours(-1),
+                _ => return vec![event],
+            };
+            drop(clock);
+            self.5.call(ctx, &self.7, time);
+        } else if event.downcast_ref::<TickEvent>().is_some() {
+            self.refresh();
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for TimePicker {
+    fn accessibility(&self) -> AccessNode {
+        let clock = self.4.lock().unwrap();
+        let value = match clock.zones.get(clock.zone) {
+            Some((name, _)) => format!("{} {name}", clock.time.format("%H:%M")),
+            None => clock.time.format("%H:%M").to_string(),
+        };
+        AccessNode::new(Role::TimeInput).value(value).focused(self.3.is_focused())
+    }
+}
+
+impl TimePicker {
+    pub fn new(theme: &Theme, time: NaiveTime, on_change: impl FnMut(&mut Context, &Theme, NaiveTime) + Send + Sync + 'static) -> Self {
+        let twenty_four = theme.locale().number_format().twenty_four_hour;
+        let clock = Arc::new(Mutex::new(Clock { time, step: 1, twenty_four, zones: vec![], zone: 0 }));
+        let on_change = OnTime::new(on_change);
+        let row = ClockRow::new(theme, &clock, &on_change);
+
+        let mut picker = TimePicker(Column::center(16.0), row, None, FocusRing::underline(theme), clock, on_change, None, theme.clone());
+        picker.refresh();
+        picker
+    }
+
+    /// Moves minutes in steps of `minutes` (e.g. 5 or 15), snapping the current time down to the step.
+    /// Steps are rounded down to a divisor of 60 so the minutes wrap onto the same grid.
+    pub fn step(self, minutes: u32) -> Self {
+        self.rebuild(|clock| {
+            clock.step = Clock::step_for(minutes);
+            clock.time = clock.time.with_minute(clock.time.minute() - clock.time.minute() % clock.step).unwrap_or(clock.time);
+        })
+    }
+
+    pub fn twenty_four_hour(self, twenty_four: bool) -> Self {
+        self.rebuild(|clock| clock.twenty_four = twenty_four)
+    }
+
+    /// Adds a selector cycling through named UTC offsets, starting at `selected`.
+    pub fn timezones(mut self, theme: &Theme, zones: Vec<(String, FixedOffset)>, selected: usize) -> Self {
+        let has_zones = !zones.is_empty();
+        {
+            let mut clock = self.4.lock().unwrap();
+            clock.zone = selected.min(zones.len().saturating_sub(1));
+            clock.zones = zones;
+        }
+        self.2 = has_zones.then(|| ZoneSelector::new(theme, &self.4, &self.5));
+        self.6 = None;
+        self.refresh();
+        self
+    }
+
+    pub fn value(&self) -> NaiveTime {self.4.lock().unwrap().time}
+
+    /// The selected timezone's offset, if the picker has a timezone selector.
+    pub fn offset(&self) -> Option<FixedOffset> {
+        let clock = self.4.lock().unwrap();
+        clock.zones.get(clock.zone).map(|(_, offset)| *offset)
+    }
+
+    /// The picked time on `date` in the selected timezone.
+    pub fn value_on(&self, date: NaiveDate) -> Option<DateTime<FixedOffset>> {
+        self.value_in(date, &self.offset()?)
+    }
+
+    /// The picked time on `date` in any chrono timezone, e.g. a `chrono_tz::Tz`.
+    pub fn value_in<Tz: TimeZone>(&self, date: NaiveDate, zone: &Tz) -> Option<DateTime<Tz>> {
+        zone.from_local_datetime(&date.and_time(self.value())).single()
+    }
+
+    fn rebuild(mut self, apply: impl FnOnce(&mut Clock)) -> Self {
+        apply(&mut self.4.lock().unwrap());
+        self.1 = ClockRow::new(&self.7, &self.4, &self.5);
+        self.6 = None;
+        self.refresh();
+        self
+    }
+
+    /// Updates the shown hour, minute, AM/PM and timezone after a change.
+    fn refresh(&mut self) {
+        let clock = self.4.lock().unwrap().clone();
+        let shown = (clock.time, clock.zone);
+        if self.6 == Some(shown) { return; }
+
+        let (hour, minute, meridiem) = clock.labels(&self.7);
+        self.1.1.2.spans[0] = hour;
+        self.1.3.2.spans[0] = minute;
+        if let Some(stepper) = &mut self.1.4 { stepper.2.spans[0] = meridiem; }
+        if let (Some(selector), Some((name, _))) = (&mut self.2, clock.zones.get(clock.zone)) { selector.2.spans[0] = name.to_string(); }
+        self.6 = Some(shown);
+    }
+}
+
+/// The picked time and how it may be changed.
+#[derive(Debug, Clone)]
+struct Clock {
+    time: NaiveTime,
+    step: u32,
+    twenty_four: bool,
+    zones: Vec<(String, FixedOffset)>,
+    zone: usize,
+}
+
+impl Clock {
+    /// The minute steps that divide an hour evenly.
+    const STEPS: [u32; 11] = [1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30];
+
+    fn step_for(minutes: u32) -> u32 {
+        Self::STEPS.into_iter().rev().find(|step| *step <= minutes).unwrap_or(1)
+    }
+
+    fn hours(&mut self, delta: i64) -> NaiveTime {
+        self.time = self.time.overflowing_add_signed(TimeDelta::hours(delta)).0;
+        self.time
+    }
+
+    /// Moves by `delta` steps, wrapping within the hour like a wheel.
+    fn minutes(&mut self, delta: i64) -> NaiveTime {
+        let minute = (self.time.minute() as i64 + delta * self.step as i64).rem_euclid(60);
+        self.time = self.time.with_minute(minute as u32).unwrap_or(self.time);
+        self.time
+    }
+
+    fn zone(&mut self, delta: isize) {
+        if !self.zones.is_empty() { self.zone = (self.zone as isize + delta).rem_euclid(self.zones.len() as isize) as usize; }
+    }
+
+    fn labels(&self, theme: &Theme) -> (String, String, String) {
+        let (is_pm, hour12) = self.time.hour12();
+        let hour = match self.twenty_four {
+            true => format!("{:02}", self.time.hour()),
+            false => hour12.to_string(),
+        };
+        let meridiem = theme.tr(if is_pm { "time-pm" } else { "time-am" });
+        (hour, format!("{:02}", self.time.minute()), meridiem)
+    }
+}
+
+type OnTime = Shared<dyn FnMut(&mut Context, &Theme, NaiveTime) + Send + Sync>;
+
+impl OnTime {
+    fn new(callback: impl FnMut(&mut Context, &Theme, NaiveTime) + Send + Sync + 'static) -> Self {Shared(Arc::new(Mutex::new(callback)))}
+    fn call(&self, ctx: &mut Context, theme: &Theme, time: NaiveTime) {(self.lock())(ctx, theme, time)}
+}
+
+#[derive(Debug, Component, Clone)]
+struct ClockRow(Row, Stepper, Text, Stepper, Option<Stepper>);
+impl OnEvent for ClockRow {}
+
+impl ClockRow {
+    fn new(theme: &Theme, clock: &Arc<Mutex<Clock>>, on_change: &OnTime) -> Self {
+        let adjust = |change: fn(&mut Clock) -> NaiveTime| {
+            let (clock, on_change) = (clock.clone(), on_change.clone());
+            move |ctx: &mut Context, theme: &Theme| {
+                let time = change(&mut clock.lock().unwrap());
+                on_change.call(ctx, theme, time);
+            }
+        };
+
+        let twenty_four = clock.lock().unwrap().twenty_four;
+        let hour = Stepper::new(theme, adjust(|c| c.hours(1)), adjust(|c| c.hours(-1)));
+        let minute = Stepper::new(theme, adjust(|c| c.minutes(1)), adjust(|c| c.minutes(-1)));
+        let meridiem = (!twenty_four).then(|| Stepper::new(theme, adjust(|c| c.hours(12)), adjust(|c| c.hours(-12))));
+        let colon = Text::new(theme, ":", TextSize::H2, TextStyle::Heading, Align::Center, None);
+        ClockRow(Row::new(8.0, Offset::Center, Size::Fit, Padding::default()), hour, colon, minute, meridiem)
+    }
+}
+
+/// An up button, a value and a down button.
+#[derive(Debug, Component, Clone)]
+struct Stepper(Column, GhostIconButton, Text, GhostIconButton);
+impl OnEvent for Stepper {}
+
+impl Stepper {
+    fn new(theme: &Theme, up: impl FnMut(&mut Context, &Theme) + Clone + 'static, down: impl FnMut(&mut Context, &Theme) + Clone + 'static) -> Self {
+        let value = Text::new(theme, "", TextSize::H2, TextStyle::Heading, Align::Center, None);
+        Stepper(Column::center(4.0), GhostIconButton::new(theme, Icons::Up, up), value, GhostIconButton::new(theme, Icons::Down, down))
+    }
+}
+
+#[derive(Debug, Component, Clone)]
+struct ZoneSelector(Directional, GhostIconButton, Text, GhostIconButton);
+impl OnEvent for ZoneSelector {}
+
+impl ZoneSelector {
+    fn new(theme: &Theme, clock: &Arc<Mutex<Clock>>, on_change: &OnTime) -> Self {
+        let cycle = |delta: isize| {
+            let (clock, on_change) = (clock.clone(), on_change.clone());
+            move |ctx: &mut Context, theme: &Theme| {
+                let mut clock = clock.lock().unwrap();
+                clock.zone(delta);
+                let time = clock.time;
+                drop(clock);
+                on_change.call(ctx, theme, time);
+            }
+        };
+
+        let (back, forward) = match theme.locale().is_rtl() {
+            true => (Icons::Right, Icons::Left),
+            false => (Icons::Left, Icons::Right),
+        };
+        let name = Text::new(theme, "", TextSize::Md, TextStyle::Primary, Align::Center, None);
+        let layout = Row::new(8.0, Offset::Center, Size::Fit, Padding::default());
+        ZoneSelector(Directional::new(layout, theme.locale()), GhostIconButton::new(theme, back, cycle(-1)), name, GhostIconButton::new(theme, forward, cycle(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock(hour: u32, minute: u32, step: u32) -> Clock {
+        let time = NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+        Clock { time, step: Clock::step_for(step), twenty_four: true, zones: vec![], zone: 0 }
+    }
+
+    #[test]
+    fn steps_round_down_to_divisors_of_an_hour() {
+        assert_eq!(Clock::step_for(0), 1);
+        assert_eq!(Clock::step_for(7), 6);
+        assert_eq!(Clock::step_for(25), 20);
+        assert_eq!(Clock::step_for(60), 30);
+        assert!(Clock::STEPS.iter().all(|step| 60 % step == 0));
+    }
+
+    #[test]
+    fn minutes_wrap_onto_the_same_grid() {
+        let mut clock = clock(9, 48, 7);
+        assert_eq!(clock.minutes(1).minute(), 54);
+        assert_eq!(clock.minutes(1).minute(), 0);
+        assert_eq!(clock.minutes(-1).minute(), 54);
+        assert_eq!(clock.minutes(-10).minute(), 54);
+        assert_eq!(clock.time.hour(), 9);
+    }
+}
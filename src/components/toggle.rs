@@ -1,11 +1,17 @@
-use prism::event::OnEvent;
-use prism::drawable::Component;
+use prism::event::{OnEvent, Event};
+use prism::drawable::{Component, SizedTree};
 use prism::canvas::{Shape, Align};
 use prism::layout::{Column, Stack, Size, Offset, Padding};
 use prism::display::Bin;
 use prism::Context;
 
-use crate::theme::Theme;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::Shared;
+use crate::theme::{Theme, ThemeChanged};
+use crate::accessibility::{Accessible, AccessNode, Role};
+use crate::interface::focus::{FocusEvent, FocusRing};
 use crate::components::text::{TextSize, TextStyle, ExpandableText};
 use crate::components::{Rectangle, Circle};
 
@@ -13,23 +19,57 @@ use ptsd::interactions;
 
 /// Toggle
 #[derive(Debug, Component, Clone)]
-pub struct Toggle(Column, ExpandableText, pub interactions::Toggle);
-impl OnEvent for Toggle {}
+pub struct Toggle(Column, ExpandableText, pub interactions::Toggle, #[skip] Arc<AtomicBool>, FocusRing, #[skip] OnToggle, #[skip] Theme);
+impl OnEvent for Toggle {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(FocusEvent::Activate) = event.downcast_ref::<FocusEvent>() && self.4.is_focused() {
+            let is_on = !self.3.load(Ordering::Relaxed);
+            self.2 = Toggle::switch(&self.6, is_on, &self.5);
+            self.5.call(ctx, is_on);
+        } else if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            self.6 = theme.clone();
+            self.2 = Toggle::switch(&self.6, self.3.load(Ordering::Relaxed), &self.5);
+        }
+        vec![event]
+    }
+}
+
+impl Accessible for Toggle {
+    fn accessibility(&self) -> AccessNode {
+        AccessNode::new(Role::Switch).label(&self.1.0.spans[0]).checked(self.3.load(Ordering::Relaxed)).focused(self.4.is_focused())
+    }
+}
+
 impl Toggle {
     pub fn new(theme: &Theme, label: &str, is_selected: bool, mut on_click: impl FnMut(&mut Context, &Theme, bool) + Send + Sync + 'static) -> Self {
         let label = ExpandableText::new(theme, label, TextSize::H5, TextStyle::Heading, Align::Left, None);
 
-        let on = _Toggle::new(theme, true);
-        let off = _Toggle::new(theme, false); 
-
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context, is_on: bool| (on_click)(ctx, &theme, is_on));
-        Toggle(Column::start(16.0), label, interactions::Toggle::new(on, off, is_selected, callback))
+        let state = Arc::new(AtomicBool::new(is_selected));
+        let (callback_theme, shared) = (theme.clone(), state.clone());
+        let on_toggle = OnToggle::new(move |ctx: &mut Context, is_on: bool| {
+            shared.store(is_on, Ordering::Relaxed);
+            (on_click)(ctx, &callback_theme, is_on)
+        });
+        let switch = Toggle::switch(theme, is_selected, &on_toggle);
+        Toggle(Column::start(16.0), label, switch, state, FocusRing::underline(theme), on_toggle, theme.clone())
     }
 
     pub fn default(theme: &Theme) -> Self {
         Self::new(theme, "Toggle", true, |_: &mut Context, _: &Theme, is_on: bool| println!("Toggle is now {is_on}"))
     }
+
+    fn switch(theme: &Theme, is_on: bool, on_toggle: &OnToggle) -> interactions::Toggle {
+        let on_toggle = on_toggle.clone();
+        let callback = Box::new(move |ctx: &mut Context, is_on: bool| on_toggle.call(ctx, is_on));
+        interactions::Toggle::new(_Toggle::new(theme, true), _Toggle::new(theme, false), is_on, callback)
+    }
+}
+
+type OnToggle = Shared<dyn FnMut(&mut Context, bool) + Send + Sync>;
+
+impl OnToggle {
+    fn new(callback: impl FnMut(&mut Context, bool) + Send + Sync + 'static) -> Self {Shared(Arc::new(Mutex::new(callback)))}
+    fn call(&self, ctx: &mut Context, is_on: bool) {(self.lock())(ctx, is_on)}
 }
 
 #[derive(Debug, Component, Clone)]
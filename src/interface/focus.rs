@@ -0,0 +1,158 @@
+use std::sync::{Arc, Mutex};
+
+use prism::Context;
+use prism::event::{self, OnEvent, Event, MouseEvent, MouseState, MouseButton};
+use prism::drawable::{Component, SizedTree};
+use prism::display::Opt;
+use prism::layout::{Area, Stack, Size, Offset, Padding};
+
+use crate::theme::{Theme, Color, ThemeChanged};
+use crate::components::Rectangle;
+
+/// Drives keyboard focus between interactive components.
+///
+/// The [`Interface`](crate::interface::general::Interface) turns Tab/Shift-Tab into
+/// a `Scan` followed by a `Set` on the next tick, and Enter/Space into `Activate`.
+/// Every [`FocusRing`] numbers itself in tree order while the events pass through,
+/// so focus follows the visual order of `Content` and `Bumper`.
+#[derive(Debug, Clone)]
+pub enum FocusEvent {
+    /// Counts the focusable components and records which one currently has focus.
+    Scan(Arc<Mutex<FocusScan>>),
+    /// Moves focus to the component at this index.
+    Set(usize, Arc<Mutex<usize>>),
+    /// Activates the focused component, like a click would.
+    Activate,
+    /// Removes focus, e.g. after the user clicks with the mouse.
+    Clear,
+}
+
+impl Event for FocusEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: &[Area]) -> Vec<Option<Box<dyn Event>>> {
+        children.iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FocusScan {
+    pub count: usize,
+    pub current: Option<usize>,
+}
+
+impl FocusScan {
+    /// The index focus should move to when stepping by `delta`.
+    pub fn target(&self, delta: isize) -> Option<usize> {
+        let count = self.count as isize;
+        (count > 0).then(|| match self.current {
+            Some(current) => (current as isize + delta).rem_euclid(count) as usize,
+            None if delta < 0 => self.count - 1,
+            None => 0,
+        })
+    }
+}
+
+/// Focus bookkeeping for one component.
+///
+/// [`FocusRing`] wraps this for components that draw an indicator; components
+/// that show focus another way keep a `#[skip] FocusState` and call
+/// [`FocusState::handle`] from their own `on_event`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FocusState(bool);
+
+impl FocusState {
+    /// Updates focus for `event`, returning whether it changed.
+    pub fn handle(&mut self, event: &FocusEvent) -> bool {
+        let was = self.0;
+        match event {
+            FocusEvent::Scan(scan) => {
+                let mut scan = scan.lock().unwrap();
+                if self.0 { scan.current = Some(scan.count); }
+                scan.count += 1;
+            },
+            FocusEvent::Set(target, index) => {
+                let mut index = index.lock().unwrap();
+                self.0 = *index == *target;
+                *index += 1;
+            },
+            FocusEvent::Clear => self.0 = false,
+            FocusEvent::Activate => {},
+        }
+        was != self.0
+    }
+
+    pub fn is_focused(&self) -> bool {self.0}
+}
+
+/// The focus indicator of a single focusable component.
+///
+/// Components embed one and check [`FocusRing::is_focused`] when a
+/// [`FocusEvent::Activate`] arrives; the ring keeps track of its own focus.
+///
+/// An `outline` ring is drawn over its parent and should be the last child of a
+/// `Stack`; an `underline` ring is a bar meant to sit at the end of a `Column`.
+#[derive(Debug, Component, Clone)]
+pub struct FocusRing(Stack, Opt<Rectangle>, #[skip] FocusState);
+
+impl OnEvent for FocusRing {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(event) = event.downcast_ref::<FocusEvent>() && self.2.handle(event) {
+            self.1.display(self.2.is_focused());
+        } else if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let color = theme.colors().get(ptsd::Brand).into();
+            match self.1.inner().outline() {
+                Some(outline) => *outline = color,
+                None => *self.1.inner().background() = color,
+            }
+        }
+        vec![event]
+    }
+}
+
+impl FocusRing {
+    pub fn outline(theme: &Theme, radius: f32) -> Self {
+        let ring = Rectangle::new(Color::TRANSPARENT, radius, Some((2.0, theme.colors().get(ptsd::Brand))));
+        FocusRing(Stack::default(), Opt::new(ring, false), FocusState::default())
+    }
+
+    pub fn underline(theme: &Theme) -> Self {
+        let bar = Rectangle::new(theme.colors().get(ptsd::Brand), 1.0, None);
+        let layout = Stack(Offset::Start, Offset::Start, Size::Fill, Size::Static(2.0), Padding::default());
+        FocusRing(layout, Opt::new(bar, false), FocusState::default())
+    }
+
+    pub fn is_focused(&self) -> bool {self.2.is_focused()}
+}
+
+/// A press and release at `position`, used to activate a focused component
+/// through the same path as a real click.
+pub fn click(position: (f32, f32)) -> Vec<Box<dyn Event>> {
+    [MouseState::Pressed, MouseState::Released].into_iter().map(|state| {
+        Box::new(MouseEvent{state, position: Some(position), button: Some(MouseButton::Left)}) as Box<dyn Event>
+    }).collect()
+}
+
+/// A click at the center of the component that received `sized`.
+pub fn click_center(sized: &SizedTree) -> Vec<Box<dyn Event>> {
+    click((sized.0.0 / 2.0, sized.0.1 / 2.0))
+}
+
+/// A press outside of every component, which blurs a focused input field.
+pub fn blur() -> Box<dyn Event> {
+    Box::new(MouseEvent{state: MouseState::Pressed, position: None, button: Some(MouseButton::Left)})
+}
+
+/// Focus keys handled by the `Interface`.
+pub(crate) enum FocusKey { Next, Previous, Activate }
+
+impl FocusKey {
+    pub(crate) fn from(event: &event::KeyboardEvent) -> Option<Self> {
+        use prism::event::{Key, NamedKey, KeyboardState};
+        if event.state != KeyboardState::Pressed { return None; }
+        match &event.key {
+            Key::Named(NamedKey::Tab) if event.modifiers.shift_key() => Some(FocusKey::Previous),
+            Key::Named(NamedKey::Tab) => Some(FocusKey::Next),
+            Key::Named(NamedKey::Enter | NamedKey::Space) => Some(FocusKey::Activate),
+            _ => None,
+        }
+    }
+}
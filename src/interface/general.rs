@@ -1,12 +1,12 @@
 use prism::{drawables, Context, IS_MOBILE, IS_WEB};
-use prism::event::{self, Event, OnEvent, MouseEvent, MouseState, TickEvent};
+use prism::event::{self, Event, OnEvent, MouseEvent, MouseState, TickEvent, KeyboardEvent};
 use prism::drawable::{Drawable, Component, SizedTree};
 use prism::canvas::Align;
 use prism::display::Bin;
 use prism::layout::{Area, Column, Stack, Row, Padding, Offset, Size,  ScrollAnchor};
 
 use crate::Callback;
-use crate::theme::{Theme, Icons};
+use crate::theme::{Theme, Icons, ThemeChanged};
 use crate::components::{Rectangle, TextInput, Profile};
 use crate::components::text::{TextStyle, TextSize, ExpandableText};
 use crate::components::button::{GhostIconButton, PrimaryButton, SecondaryButton};
@@ -14,6 +14,8 @@ use crate::components::avatar::{AvatarGroup, AvatarContent};
 use crate::interface::system::MobileKeyboard;
 use crate::interface::navigation::{RootInfo, Navigator};
 use crate::interface::navigation::FlowContainer;
+use crate::interface::focus::{FocusEvent, FocusScan, FocusKey};
+use crate::locale::{Locale, Directional};
 
 use ptsd::interfaces::{Body, Navigator as PTSDNavigator};
 use ptsd::navigation::{NavigationEvent, AppPage};
@@ -54,7 +56,10 @@ pub struct Interface {
     layout: Stack,
     background: Rectangle,
     inner: ptsd::interfaces::Interface,
-    #[skip] pub on_event: Option<Box<dyn OnEventFn>>
+    #[skip] pub on_event: Option<Box<dyn OnEventFn>>,
+    #[skip] focus: Option<(isize, Arc<Mutex<FocusScan>>)>,
+    #[skip] has_focus: bool,
+    #[skip] locale: Locale,
 }
 
 impl OnEvent for Interface {
@@ -65,6 +70,26 @@ impl OnEvent for Interface {
 
         if let Some(InterfaceEvent::Disable(disable)) = event.downcast_ref::<InterfaceEvent>() {
             ctx.emit(event::Button::Disable(*disable));
+        } else if let Some(ThemeChanged(theme)) = event.downcast_mut::<ThemeChanged>() {
+            // Theme constructors start out in en-US, so a new palette keeps the app's locale unless it brings its own.
+            if !theme.has_locale() { *theme = theme.clone().with_locale(self.locale.clone()); }
+            self.locale = theme.locale().clone();
+            *self.background.background() = theme.colors().get(ptsd::Background::Primary).into();
+        } else if let Some(keyboard) = event.downcast_ref::<KeyboardEvent>() && !IS_MOBILE {
+            match FocusKey::from(keyboard) {
+                Some(FocusKey::Next) => self.scan_focus(ctx, 1),
+                Some(FocusKey::Previous) => self.scan_focus(ctx, -1),
+                Some(FocusKey::Activate) if self.has_focus => ctx.emit(FocusEvent::Activate),
+                _ => {}
+            }
+        } else if event.downcast_ref::<TickEvent>().is_some() && let Some((delta, scan)) = self.focus.take() {
+            if let Some(target) = scan.lock().unwrap().target(delta) {
+                ctx.emit(FocusEvent::Set(target, Arc::new(Mutex::new(0))));
+                self.has_focus = true;
+            }
+        } else if let Some(MouseEvent{state: MouseState::Pressed, position: Some(_), ..}) = event.downcast_ref::<MouseEvent>() && self.has_focus {
+            ctx.emit(FocusEvent::Clear);
+            self.has_focus = false;
         } //else if let Some(HardwareEvent::SafeArea(b, l, t, r)) = event.downcast_ref::<HardwareEvent>() {
         //     self.layout = Stack::new(Offset::default(), Offset::default(), Size::default(), Size::default(), Padding(*l, *t, *r, *b));
         //     println!("Setting padding to {:?}", self.layout.4);
@@ -107,9 +132,20 @@ impl Interface {
                 }
             },
             on_event: Some(on_event),
+            focus: None,
+            has_focus: false,
+            locale: theme.locale().clone(),
         }
     }
 
+    /// Starts a Tab/Shift-Tab step; focus moves on the next tick once every
+    /// focusable component has been counted.
+    fn scan_focus(&mut self, ctx: &mut Context, delta: isize) {
+        let scan = Arc::new(Mutex::new(FocusScan::default()));
+        ctx.emit(FocusEvent::Scan(scan.clone()));
+        self.focus = Some((delta, scan));
+    }
+
     fn _inner(&mut self) -> &mut Box<dyn AppPage> {
         self.inner.pages().current()
     }
@@ -273,7 +309,7 @@ impl ContentChildren {
 /// Header components can only be used inside [`Page`] components.
 #[derive(Debug, Component, Clone)]
 pub struct Header {
-    layout: Row,
+    layout: Directional,
     pub left: HeaderIcon,
     pub center: Box<dyn Drawable>,
     pub right: HeaderIcon
@@ -290,7 +326,7 @@ impl Header {
     /// A `Header` preset used for in-flow pages.
     pub fn stack(theme: &Theme, title: &str, icon: Option<(Icons, Box<dyn Callback>)>) -> Self {
         let closure = |ctx: &mut Context, _: &Theme| ctx.emit(NavigationEvent::Pop);
-        Self::_new(theme, title, Some((Icons::Left, Box::new(closure))), icon, TextSize::H4)
+        Self::_new(theme, title, Some((Self::back(theme), Box::new(closure))), icon, TextSize::H4)
     }
 
     /// A `Header` preset used for end-of-flow pages.
@@ -301,12 +337,12 @@ impl Header {
 
     pub fn messaging(ctx: &mut Context, theme: &Theme, profiles: Vec<Profile>, exact_len: usize, info: Box<dyn Callback>) -> Self {
         let closure = move |ctx: &mut Context, _: &Theme| (0..exact_len).for_each(|_| ctx.emit(NavigationEvent::Pop));
-        let l_icon = HeaderIcon::new(theme, Icons::Left, closure);
+        let l_icon = HeaderIcon::new(theme, Self::back(theme), closure);
         let r_icon = HeaderIcon::new(theme, Icons::Info, info); // this needs to navigate to info page
 
         let layout = Row::new(16.0, Offset::Center, Size::Fit, Padding(0.0, 16.0, 0.0, 16.0));
         Header {
-            layout,
+            layout: Directional::new(layout, theme.locale()),
             left: l_icon,
             center: Box::new(MessageHeader::new(theme, profiles)),
             right: r_icon
@@ -327,12 +363,17 @@ impl Header {
 
         let layout = Row::new(16.0, Offset::Center, Size::Fit, Padding(0.0, 16.0, 0.0, 16.0));
         Header {
-            layout,
+            layout: Directional::new(layout, theme.locale()),
             left: l_icon,
             center: Box::new(text),
             right: r_icon
         }
     }
+
+    /// The back arrow points toward the start of the line, which is the right edge in RTL locales.
+    fn back(theme: &Theme) -> Icons {
+        if theme.locale().is_rtl() { Icons::Right } else { Icons::Left }
+    }
 }
 
 #[derive(Debug, Clone, Component)]
@@ -341,10 +382,10 @@ impl OnEvent for MessageHeader {}
 impl MessageHeader {
     pub fn new(theme: &Theme, profiles: Vec<Profile>) -> Self {
         let title = match profiles.len() > 1 {
-            true => "Group Message".to_string(),
+            true => theme.tr("group-message"),
             false => match profiles.get(0) {
                 Some(first) => first.username.to_string(),
-                None => "New Message".to_string(),
+                None => theme.tr("new-message"),
             },
         };
         let text = ExpandableText::new(theme, &title, TextSize::H4, TextStyle::Heading, Align::Center, Some(1));
@@ -379,7 +420,14 @@ impl HeaderIcon {
 
 #[derive(Component, Debug, Clone)]
 pub struct Bumper {layout: Stack, background: Rectangle, content: BumperContent}
-impl OnEvent for Bumper {}
+impl OnEvent for Bumper {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            *self.background.background() = theme.colors().get(ptsd::Background::Primary).into();
+        }
+        vec![event]
+    }
+}
 
 impl Bumper {
     /// A `Bumper` preset used for home pages.
@@ -398,7 +446,7 @@ impl Bumper {
         on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static, 
         secondary: Option<(String, Box<dyn Callback>)>, 
     ) -> Self {
-        let mut content = drawables![PrimaryButton::new(theme, label.unwrap_or("Continue"), Box::new(on_click))];
+        let mut content = drawables![PrimaryButton::new(theme, &label.map(str::to_string).unwrap_or_else(|| theme.tr("continue")), Box::new(on_click))];
         if let Some((l, c)) = secondary { content.push(Box::new(SecondaryButton::large(theme, &l, c))); }
         let (layout, background) = Self::layout(theme);
         Bumper { layout, background, content: BumperContent::new(content) }
@@ -411,7 +459,7 @@ impl Bumper {
             None => ctx.emit(NavigationEvent::Reset),
         };
         
-        let content = SecondaryButton::large(theme, "Done", Box::new(closure));
+        let content = SecondaryButton::large(theme, &theme.tr("done"), Box::new(closure));
         let (layout, background) = Self::layout(theme);
         Bumper { layout, background, content: BumperContent::new(vec![Box::new(content)]) }
     }
@@ -486,6 +534,9 @@ pub struct Screen(Stack, Pages, Option<Bin<Stack, Rectangle>>);
 impl OnEvent for Screen {
     fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, mut event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
         if let Some(NavigationEvent::Push(_, v)) = event.downcast_mut::<NavigationEvent>() {*v = vec![1];}
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() && let Some(border) = &mut self.2 {
+            *border.inner().background() = theme.colors().get(ptsd::Outline::Secondary).into();
+        }
         vec![event]
     }
 }
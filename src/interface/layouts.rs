@@ -0,0 +1,116 @@
+//! Letter layouts for the on-screen [`MobileKeyboard`](crate::interface::system::MobileKeyboard).
+//!
+//! A layout is plain data: three rows of keys and the alternates offered when a
+//! key is long-pressed. Apps can define their own and install them on the locale:
+//!
+//! ```rust
+//! let dvorak = KeyboardLayout::new("DV", ["' , . p y f g c r l", "a o e u i d h t n s", "; q j k x b m w v z"]);
+//! let theme = Theme::default().with_locale(Locale::new("en").with_keyboards(vec![dvorak, KeyboardLayout::qwerty()]));
+//! ```
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardLayout {
+    /// A short label such as `"FR"`, used to tell layouts apart.
+    pub name: String,
+    pub rows: [Vec<String>; 3],
+    pub accents: HashMap<String, Vec<String>>,
+}
+
+impl KeyboardLayout {
+    /// A layout from three rows of space-separated keys.
+    pub fn new(name: &str, rows: [&str; 3]) -> Self {
+        KeyboardLayout {
+            name: name.to_string(),
+            rows: rows.map(|row| row.split_whitespace().map(str::to_string).collect()),
+            accents: HashMap::new(),
+        }
+    }
+
+    /// Offers the space-separated `alternates` when `key` is long-pressed.
+    pub fn accents(mut self, key: &str, alternates: &str) -> Self {
+        self.accents.insert(key.to_string(), alternates.split_whitespace().map(str::to_string).collect());
+        self
+    }
+
+    pub fn alternates(&self, key: &str) -> &[String] {
+        self.accents.get(&key.to_lowercase()).map(|a| a.as_slice()).unwrap_or_default()
+    }
+
+    pub fn qwerty() -> Self {
+        KeyboardLayout::new("EN", ["q w e r t y u i o p", "a s d f g h j k l", "z x c v b n m"])
+            .accents("a", "à á â ä æ ã å ā")
+            .accents("c", "ç ć č")
+            .accents("e", "è é ê ë ē ė ę")
+            .accents("i", "î ï í ī į ì")
+            .accents("n", "ñ ń")
+            .accents("o", "ô ö ò ó œ ø ō õ")
+            .accents("s", "ß ś š")
+            .accents("u", "û ü ù ú ū")
+            .accents("y", "ÿ")
+            .accents("z", "ž ź ż")
+    }
+
+    pub fn azerty() -> Self {
+        KeyboardLayout::new("FR", ["a z e r t y u i o p", "q s d f g h j k l m", "w x c v b n '"])
+            .accents("a", "à â æ")
+            .accents("c", "ç")
+            .accents("e", "é è ê ë")
+            .accents("i", "î ï")
+            .accents("o", "ô œ")
+            .accents("u", "ù û ü")
+            .accents("y", "ÿ")
+    }
+
+    pub fn qwertz() -> Self {
+        KeyboardLayout::new("DE", ["q w e r t z u i o p ü", "a s d f g h j k l ö ä", "y x c v b n m"])
+            .accents("a", "à á â")
+            .accents("e", "é è ê")
+            .accents("o", "ó ò ô")
+            .accents("s", "ß")
+            .accents("u", "ú ù û")
+    }
+
+    /// Swedish/Finnish keys, with the Danish and Norwegian letters on long-press.
+    pub fn nordic() -> Self {
+        KeyboardLayout::new("SV", ["q w e r t y u i o p å", "a s d f g h j k l ö ä", "z x c v b n m"])
+            .accents("ä", "æ")
+            .accents("ö", "ø")
+            .accents("a", "á")
+            .accents("e", "é")
+    }
+
+    pub fn cyrillic() -> Self {
+        KeyboardLayout::new("RU", ["й ц у к е н г ш щ з х", "ф ы в а п р о л д ж э", "я ч с м и т ь б ю"])
+            .accents("е", "ё")
+            .accents("ь", "ъ")
+    }
+
+    pub fn greek() -> Self {
+        KeyboardLayout::new("EL", ["ς ε ρ τ υ θ ι ο π", "α σ δ φ γ η ξ κ λ", "ζ χ ψ ω β ν μ"])
+            .accents("α", "ά")
+            .accents("ε", "έ")
+            .accents("η", "ή")
+            .accents("ι", "ί ϊ ΐ")
+            .accents("ο", "ό")
+            .accents("υ", "ύ ϋ ΰ")
+            .accents("ω", "ώ")
+    }
+
+    /// The layouts a speaker of `language` expects, native layout first.
+    pub fn for_language(language: &str) -> Vec<Self> {
+        match language {
+            "fr" => vec![Self::azerty(), Self::qwerty()],
+            "de" => vec![Self::qwertz(), Self::qwerty()],
+            "sv" | "fi" | "da" | "no" | "nb" | "nn" => vec![Self::nordic(), Self::qwerty()],
+            "ru" | "bg" | "be" => vec![Self::cyrillic(), Self::qwerty()],
+            "el" => vec![Self::greek(), Self::qwerty()],
+            _ => vec![Self::qwerty()],
+        }
+    }
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {KeyboardLayout::qwerty()}
+}
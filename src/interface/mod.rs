@@ -1,3 +1,5 @@
 pub mod general;
 pub mod navigation;
-pub mod system;
\ No newline at end of file
+pub mod system;
+pub mod layouts;
+pub mod focus;
\ No newline at end of file
@@ -1,6 +1,6 @@
 use prism::{Context, drawables};
-use prism::event::OnEvent;
-use prism::drawable::Component;
+use prism::event::{OnEvent, Event};
+use prism::drawable::{Component, SizedTree};
 use prism::canvas::{Align, Image};
 use prism::display::Bin;
 use prism::layout::{Row, Column, Size, Padding, Offset, Stack};
@@ -9,8 +9,10 @@ use ptsd::interactions;
 
 pub use ptsd::navigation::{NavigationEvent, AppPage, Flow, FlowContainer};
 
-use crate::theme::{self, Theme, Color, Variant, Icons};
+use crate::Callback;
+use crate::theme::{self, Theme, Color, Variant, Icons, ThemeChanged};
 use crate::components::{Icon, AspectRatioImage, Rectangle};
+use crate::locale::Directional;
 use crate::components::text::{TextStyle, Text};
 use crate::components::avatar::{Avatar, AvatarContent, AvatarSize};
 use crate::components::button::{Button, ButtonStyle, ButtonSize, ButtonWidth, IconButton};
@@ -46,50 +48,76 @@ impl RootInfo {
 
 
 #[derive(Debug, Component, Clone)]
-pub struct NavigatorSelectable(Stack, interactions::Selectable);
-impl OnEvent for NavigatorSelectable {}
+pub struct NavigatorSelectable(Stack, interactions::Selectable, #[skip] NavigatorContent, #[skip] Box<dyn Callback>, #[skip] uuid::Uuid);
+impl OnEvent for NavigatorSelectable {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let is_selected = self.1.is_selected();
+            *self = Self::build(theme, self.2.clone(), self.3.clone(), is_selected, self.4);
+        }
+        vec![event]
+    }
+}
+
 impl NavigatorSelectable {
-    pub fn desktop_icon(theme: &Theme, icon: Icons, label: &str, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static, is_selected: bool, group_id: uuid::Uuid) -> Self {
-        let colors = theme::Button::get(theme.colors(), Variant::Ghost);
-        let [default, selected] = [colors.default, colors.pressed].map(|colors| {
-            let font_size = ButtonSize::Large.font();
-            let icon_size = ButtonSize::Large.icon();
-            let text = Text::new(theme, label, font_size, TextStyle::Label(colors.label), Align::Left, None);
-            let icon = Icon::new(theme, icon, Some(colors.label), icon_size);
-            Button::new(drawables![icon, text], ButtonSize::Large, ButtonWidth::Fill, Offset::Start, colors.background, colors.outline)
-        });
+    pub fn desktop_icon(theme: &Theme, icon: Icons, label: &str, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static, is_selected: bool, group_id: uuid::Uuid) -> Self {
+        Self::build(theme, NavigatorContent::DesktopIcon(icon, label.to_string()), Box::new(on_click), is_selected, group_id)
+    }
 
-        let theme = theme.clone();
-        let callback = move |ctx: &mut Context| (on_click)(ctx, &theme);
-        NavigatorSelectable(Stack::default(), interactions::Selectable::new(default, selected, is_selected, false, callback, group_id))
+    pub fn desktop_avatar(theme: &Theme, avatar: AvatarContent, label: &str, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static, is_selected: bool, group_id: uuid::Uuid) -> Self {
+        Self::build(theme, NavigatorContent::DesktopAvatar(avatar, label.to_string()), Box::new(on_click), is_selected, group_id)
     }
 
-    pub fn desktop_avatar(theme: &Theme, avatar: AvatarContent, label: &str, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static, is_selected: bool, group_id: uuid::Uuid) -> Self {
-        let colors = theme::Button::get(theme.colors(), Variant::Ghost);
-        let [default, selected] = [colors.default, colors.pressed].map(|colors| {
-            let font_size = ButtonSize::Large.font();
-            let text = Text::new(theme, label, font_size, TextStyle::Label(colors.label), Align::Left, None);
-            let avatar = Avatar::new(theme, avatar.clone(), None, false, AvatarSize::Xs, None);
-            Button::new(drawables![avatar, text], ButtonSize::Large, ButtonWidth::Fill, Offset::Start, colors.background, colors.outline)
-        });
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        NavigatorSelectable(Stack::default(), interactions::Selectable::new(default, selected, is_selected, false, callback, group_id))
+    pub fn mobile(theme: &Theme, icon: Icons, on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static, is_selected: bool, group_id: uuid::Uuid) -> Self {
+        Self::build(theme, NavigatorContent::Mobile(icon), Box::new(on_click), is_selected, group_id)
     }
 
-    pub fn mobile(theme: &Theme, icon: Icons, mut on_click: impl FnMut(&mut Context, &Theme) + Clone + 'static, is_selected: bool, group_id: uuid::Uuid) -> Self {
+    fn build(theme: &Theme, content: NavigatorContent, on_click: Box<dyn Callback>, is_selected: bool, group_id: uuid::Uuid) -> Self {
         let colors = theme::Button::get(theme.colors(), Variant::Ghost);
-        let [default, selected] = [colors.disabled, colors.default].map(|colors| {
-            let layout = Stack::new(Offset::Center, Offset::Center, Size::Fit, Size::Fit, Padding(24.0, 0.0, 24.0, 0.0));
-            Bin(layout, IconButton::new(theme, icon, ButtonStyle::Ghost, ButtonSize::Medium, colors.background, colors.outline, colors.label))
-        });
-
-        let theme = theme.clone();
-        let callback = Box::new(move |ctx: &mut Context| (on_click)(ctx, &theme));
-        NavigatorSelectable(Stack::default(), interactions::Selectable::new(default, selected, is_selected, false, callback, group_id))
+        let (mut callback, shared) = (on_click.clone(), theme.clone());
+        let callback = Box::new(move |ctx: &mut Context| (callback)(ctx, &shared));
+
+        let selectable = match &content {
+            NavigatorContent::DesktopIcon(icon, label) => {
+                let [default, selected] = [colors.default, colors.pressed].map(|colors| {
+                    let font_size = ButtonSize::Large.font();
+                    let icon_size = ButtonSize::Large.icon();
+                    let text = Text::new(theme, label, font_size, TextStyle::Label(colors.label), Align::Left, None);
+                    let icon = Icon::new(theme, *icon, Some(colors.label), icon_size);
+                    Button::new(drawables![icon, text], ButtonSize::Large, ButtonWidth::Fill, Offset::Start, colors.background, colors.outline)
+                });
+                interactions::Selectable::new(default, selected, is_selected, false, callback, group_id)
+            },
+            NavigatorContent::DesktopAvatar(avatar, label) => {
+                let [default, selected] = [colors.default, colors.pressed].map(|colors| {
+                    let font_size = ButtonSize::Large.font();
+                    let text = Text::new(theme, label, font_size, TextStyle::Label(colors.label), Align::Left, None);
+                    let avatar = Avatar::new(theme, avatar.clone(), None, false, AvatarSize::Xs, None);
+                    Button::new(drawables![avatar, text], ButtonSize::Large, ButtonWidth::Fill, Offset::Start, colors.background, colors.outline)
+                });
+                interactions::Selectable::new(default, selected, is_selected, false, callback, group_id)
+            },
+            NavigatorContent::Mobile(icon) => {
+                let [default, selected] = [colors.disabled, colors.default].map(|colors| {
+                    let layout = Stack::new(Offset::Center, Offset::Center, Size::Fit, Size::Fit, Padding(24.0, 0.0, 24.0, 0.0));
+                    Bin(layout, IconButton::new(theme, *icon, ButtonStyle::Ghost, ButtonSize::Medium, colors.background, colors.outline, colors.label))
+                });
+                interactions::Selectable::new(default, selected, is_selected, false, callback, group_id)
+            },
+        };
+
+        NavigatorSelectable(Stack::default(), selectable, content, on_click, group_id)
     }
 }
 
+/// The icon, avatar or label a [`NavigatorSelectable`] shows.
+#[derive(Debug, Clone)]
+enum NavigatorContent {
+    DesktopIcon(Icons, String),
+    DesktopAvatar(AvatarContent, String),
+    Mobile(Icons),
+}
+
 // /// Selects the [`NavigationButton`] with the given [`uuid::Uuid`].
 // #[derive(Debug, Clone)]
 // pub struct NavigatorSelect(pub uuid::Uuid);
@@ -117,14 +145,25 @@ pub(crate) enum Navigator {
     },
 
     Web {
-        layout: Row, 
+        layout: Directional, 
         brandmark: Image, 
         spacer: Bin<Stack, Rectangle>, 
         content: ButtonRow
     }
 }
 
-impl OnEvent for Navigator {}
+impl OnEvent for Navigator {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            match self {
+                Navigator::Desktop {brandmark, ..} | Navigator::Web {brandmark, ..} => brandmark.image = theme.brand().wordmark.clone(),
+                Navigator::Mobile {background, ..} => *background.background() = theme.colors().get(ptsd::Background::Primary).into(),
+            }
+        }
+        vec![event]
+    }
+}
+
 impl ptsd::interfaces::Navigator for Navigator {}
 
 impl Navigator {
@@ -180,7 +219,7 @@ impl Navigator {
         Navigator::Mobile {
             layout: Stack(Offset::Center, Offset::Start, Size::Fill, height, Padding::default()), 
             background: Rectangle::new(background, 0.0, None),
-            content: MobileNavigatorContent::new(tabs)
+            content: MobileNavigatorContent::new(theme, tabs)
         }
     }
 
@@ -199,23 +238,23 @@ impl Navigator {
 
         let bin_layout = Stack(Offset::Center, Offset::Center, Size::Fill, Size::Static(5.0), Padding::default());
         Navigator::Web {
-            layout: Row::new(32.0, Offset::Center, Size::Fit, Padding::new(48.0)),
+            layout: Directional::new(Row::new(32.0, Offset::Center, Size::Fit, Padding::new(48.0)), theme.locale()),
             brandmark: AspectRatioImage::new(wordmark, (150.0, 35.0)),
             spacer: Bin (bin_layout, Rectangle::new(Color::TRANSPARENT, 0.0, None)),
-            content: ButtonRow::new(buttons)
+            content: ButtonRow::new(theme, buttons)
         }
     }
 }
 
 
 #[derive(Debug, Component, Clone)]
-pub struct MobileNavigatorContent(Row, Vec<NavigatorSelectable>);
+pub struct MobileNavigatorContent(Directional, Vec<NavigatorSelectable>);
 impl OnEvent for MobileNavigatorContent {}
 
 impl MobileNavigatorContent {
-    fn new(tabs: Vec<NavigatorSelectable>) -> Self {
+    fn new(theme: &Theme, tabs: Vec<NavigatorSelectable>) -> Self {
         let layout = Row::new(0.0, Offset::Center, Size::Fit, Padding(0.0, 8.0, 0.0, 8.0));
-        MobileNavigatorContent(layout, tabs)
+        MobileNavigatorContent(Directional::new(layout, theme.locale()), tabs)
     }
 }
 
@@ -230,12 +269,12 @@ impl ButtonColumn {
 }
 
 #[derive(Debug, Component, Clone)]
-pub struct ButtonRow(Row, Vec<NavigatorSelectable>);
+pub struct ButtonRow(Directional, Vec<NavigatorSelectable>);
 impl OnEvent for ButtonRow {}
 
 impl ButtonRow {
-    fn new(buttons: Vec<NavigatorSelectable>) -> Self {
-        ButtonRow(Row::center(8.0), buttons)
+    fn new(theme: &Theme, buttons: Vec<NavigatorSelectable>) -> Self {
+        ButtonRow(Directional::new(Row::center(8.0), theme.locale()), buttons)
     }
 
     // fn buttons(&mut self) -> &mut Vec<NavigatorSelectable> {&mut self.1}
@@ -1,4 +1,4 @@
-use prism::event::{self, KeyboardState, KeyboardEvent, OnEvent, Event, NamedKey, Modifiers};
+use prism::event::{self, KeyboardState, KeyboardEvent, OnEvent, Event, NamedKey, Modifiers, MouseEvent, MouseState, TickEvent};
 use prism::canvas::{Align, Image};
 use prism::{emitters, Context};
 use prism::drawable::{Drawable, Component, SizedTree};
@@ -8,15 +8,26 @@ use prism::display::{Bin, Enum};
 use ptsd::interfaces::ShowKeyboard;
 use ptsd::interactions;
 
-use crate::theme::{Theme, Color, Icons};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::components::text::{Text, TextStyle, TextSize};
+use crate::theme::{Theme, Color, Icons, ThemeChanged};
+use crate::interface::layouts::KeyboardLayout;
+
+use crate::components::text::{Text, TextStyle, TextSize, EditEvent};
 use crate::components::{Rectangle, Icon};
 use crate::components::button::GhostIconButton;
 
 #[derive(Component, Debug, Clone)]
 pub struct MobileKeyboard(Stack, Rectangle, KeyboardContent);
-impl OnEvent for MobileKeyboard {}
+impl OnEvent for MobileKeyboard {
+    fn on_event(&mut self, _ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            *self.1.background() = theme.colors().get(ptsd::Background::Secondary).into();
+        }
+        vec![event]
+    }
+}
 
 impl MobileKeyboard {
     pub fn new(theme: &Theme) -> Self {
@@ -31,20 +42,53 @@ impl MobileKeyboard {
 }
 
 #[derive(Component, Debug, Clone)]
-struct KeyboardContent(Column, KeyboardHeader, KeyboardRow, KeyboardRow, KeyboardRow, KeyboardRow, #[skip] Theme);
+struct KeyboardContent(Column, KeyboardHeader, Option<KeyRow>, KeyboardRow, KeyboardRow, KeyboardRow, KeyboardRow, #[skip] Theme, #[skip] Vec<KeyboardLayout>, #[skip] usize, #[skip] InputType);
+// Header, Accent popup, Top, Middle, Bottom, Modifier, Theme, Layouts, Current layout, Input type
 
 impl KeyboardContent {
     fn new(theme: &Theme) -> Self {
+        let layouts = theme.locale().keyboards();
         KeyboardContent(
             Column::new(0.0, Offset::Center, Size::Fit, Padding(8.0, 8.0, 8.0, 8.0), None),
             KeyboardHeader::new(theme),
-            KeyboardRow::top(theme, 0, false),
-            KeyboardRow::middle(theme, 0, false),
-            KeyboardRow::bottom(theme, 0, false),
-            KeyboardRow::modifier(theme, false),
-            theme.clone()
+            None,
+            KeyboardRow::top(theme, &layouts[0], 0, false),
+            KeyboardRow::middle(theme, &layouts[0], 0, false),
+            KeyboardRow::bottom(theme, &layouts[0], 0, false),
+            KeyboardRow::modifier(theme, false, layouts.len() > 1, shortcuts(InputType::Text)),
+            theme.clone(),
+            layouts,
+            0,
+            InputType::Text,
         )
     }
+
+    /// The shown page and capslock state; a digit pad has neither.
+    fn state(&mut self) -> (usize, bool) {
+        let caps = self.5.capslock().as_ref().is_some_and(|c| c.status());
+        let page = self.6.paginator().as_ref().map(|p| p.status()).unwrap_or_default();
+        (page, caps)
+    }
+
+    fn letters(&mut self, page: usize, caps: bool) {
+        let (theme, layout) = (&self.7, &self.8[self.9]);
+        if let Some((digits, last)) = pad(self.10, theme.locale().number_format().decimal) {
+            self.3 = KeyboardRow::pad(theme, &digits.rows[0], &digits, false);
+            self.4 = KeyboardRow::pad(theme, &digits.rows[1], &digits, false);
+            self.5 = KeyboardRow::pad(theme, &digits.rows[2], &digits, false);
+            self.6 = KeyboardRow::pad(theme, &last, &digits, true);
+            return;
+        }
+
+        self.3 = KeyboardRow::top(theme, layout, page, caps);
+        self.4 = KeyboardRow::middle(theme, layout, page, caps);
+        self.5 = KeyboardRow::bottom(theme, layout, page, caps);
+    }
+
+    fn modifier(&mut self, caps: bool) {
+        if matches!(self.10, InputType::Number | InputType::Decimal | InputType::Phone) { return; }
+        self.6 = KeyboardRow::modifier(&self.7, caps, self.8.len() > 1, shortcuts(self.10));
+    }
 }
 
 impl OnEvent for KeyboardContent {
@@ -52,21 +96,40 @@ impl OnEvent for KeyboardContent {
         if let Some(e) = event.downcast_ref::<MobileKeyboardEvent>() {
             match e {
                 MobileKeyboardEvent::Paginator(page) => {
-                    let theme = self.6.clone();
-                    let caps = self.4.capslock().as_ref().unwrap().status();
-                    self.2 = KeyboardRow::top(&theme, *page, caps);
-                    self.3 = KeyboardRow::middle(&theme, *page, caps);
-                    self.4 = KeyboardRow::bottom(&theme, *page, caps);
+                    let (_, caps) = self.state();
+                    self.letters(*page, caps);
                 },
                 MobileKeyboardEvent::Capslock(caps) => {
-                    let theme = self.6.clone();
-                    let page = self.5.paginator().as_ref().unwrap().status();
-                    self.2 = KeyboardRow::top(&theme, page, *caps);
-                    self.3 = KeyboardRow::middle(&theme, page, *caps);
-                    self.4 = KeyboardRow::bottom(&theme, page, *caps);
-                    self.5 = KeyboardRow::modifier(&theme, *caps)
+                    let (page, _) = self.state();
+                    self.letters(page, *caps);
+                    self.modifier(*caps);
+                },
+                MobileKeyboardEvent::NextLayout => {
+                    let (page, caps) = self.state();
+                    self.9 = (self.9 + 1) % self.8.len();
+                    self.2 = None;
+                    self.letters(page, caps);
+                },
+                MobileKeyboardEvent::Accents(alternates) => {
+                    self.2 = Some(KeyRow::accents(&self.7, alternates));
                 },
             }
+        } else if let Some(KeyboardType(input_type)) = event.downcast_ref::<KeyboardType>() && *input_type != self.10 {
+            self.10 = *input_type;
+            self.2 = None;
+            self.letters(0, false);
+            self.modifier(false);
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, ..}) = event.downcast_ref::<KeyboardEvent>() {
+            self.2 = None;
+        } else if let Some(ThemeChanged(theme)) = event.downcast_ref::<ThemeChanged>() {
+            let (page, caps) = self.state();
+            self.7 = theme.clone();
+            self.8 = theme.locale().keyboards();
+            self.9 = self.9.min(self.8.len() - 1);
+            self.1 = KeyboardHeader::new(theme);
+            self.2 = None;
+            self.letters(page, caps);
+            self.modifier(caps);
         }
 
         vec![event]
@@ -78,45 +141,68 @@ struct KeyRow(Row, Vec<Key>);
 impl OnEvent for KeyRow {}
 
 impl KeyRow {
-    fn new(theme: &Theme, keys: Vec<&str>, caps_on: bool) -> Self {
+    /// Letter keys; those with alternates in `layout` open an accent popup when held.
+    fn new(theme: &Theme, keys: &[String], caps_on: bool, layout: Option<&KeyboardLayout>) -> Self {
+        let case = |k: &str| match caps_on {
+            true => k.to_uppercase(),
+            false => k.to_lowercase(),
+        };
         let keys = keys.iter().map(|k| {
-            Key::character(theme, &match caps_on {
-                true => k.to_uppercase(),
-                false => k.to_lowercase(),
-            })
+            let alternates = layout.map(|l| l.alternates(k).iter().map(|a| case(a)).collect()).unwrap_or_default();
+            Key::character(theme, &case(k), alternates)
         }).collect();
         KeyRow(Row::center(0.0), keys)
     }
+
+    fn accents(theme: &Theme, alternates: &[String]) -> Self {
+        KeyRow(Row::center(0.0), alternates.iter().map(|a| Key::character(theme, a, vec![])).collect())
+    }
+
+    /// Wide keys that share the row evenly, for the digit pads.
+    fn pad(theme: &Theme, keys: &[String], layout: &KeyboardLayout) -> Self {
+        KeyRow(Row::center(0.0), keys.iter().map(|k| Key::digit(theme, k, layout.alternates(k).to_vec())).collect())
+    }
 }
 
 #[derive(Component, Debug, Clone)]
-struct KeyboardRow(Row, Option<Capslock>, Option<Paginator>, Option<KeyRow>, Option<Key>, Option<Key>);
-// Capslock, Paginator, Character Row, Spacebar, Return
+struct KeyboardRow(Row, Option<Capslock>, Option<Paginator>, Option<Key>, Option<KeyRow>, Option<Key>, Option<Key>);
+// Capslock, Paginator, Globe, Character Row, Spacebar, Return
 impl OnEvent for KeyboardRow {}
 
 impl KeyboardRow {
-    fn top(theme: &Theme, num: usize, caps_on: bool) -> Self {
-        let key_row = KeyRow::new(theme, top_keys(&num), caps_on);
-        KeyboardRow(Row::center(0.0), None, None, Some(key_row), None, None)
+    fn top(theme: &Theme, layout: &KeyboardLayout, page: usize, caps_on: bool) -> Self {
+        let key_row = KeyRow::new(theme, &keys(layout, page, 0), caps_on, (page == 0).then_some(layout));
+        KeyboardRow(Row::center(0.0), None, None, None, Some(key_row), None, None)
     }
 
-    fn middle(theme: &Theme, num: usize, caps_on: bool) -> Self {
-        let key_row = KeyRow::new(theme, mid_keys(&num), caps_on);
-        KeyboardRow(Row::center(0.0), None, None, Some(key_row), None, None)
+    fn middle(theme: &Theme, layout: &KeyboardLayout, page: usize, caps_on: bool) -> Self {
+        let key_row = KeyRow::new(theme, &keys(layout, page, 1), caps_on, (page == 0).then_some(layout));
+        KeyboardRow(Row::center(0.0), None, None, None, Some(key_row), None, None)
     }
 
-    fn bottom(theme: &Theme, num: usize, caps_on: bool) -> Self {
+    fn bottom(theme: &Theme, layout: &KeyboardLayout, page: usize, caps_on: bool) -> Self {
         let capslock = Capslock::new(theme, caps_on);
         let backspace = Key::backspace(theme);
-        let key_row = KeyRow::new(theme, bot_keys(&num), caps_on);
-        KeyboardRow(Row::center(6.0), Some(capslock), None, Some(key_row), None, Some(backspace))
+        let key_row = KeyRow::new(theme, &keys(layout, page, 2), caps_on, (page == 0).then_some(layout));
+        KeyboardRow(Row::center(6.0), Some(capslock), None, None, Some(key_row), None, Some(backspace))
     }
 
-    fn modifier(theme: &Theme, caps_on: bool) -> Self {
+    fn modifier(theme: &Theme, caps_on: bool, globe: bool, shortcuts: &[&str]) -> Self {
         let paginator = Paginator::new(theme);
+        let globe = globe.then(|| Key::globe(theme));
+        let shortcuts = (!shortcuts.is_empty()).then(|| {
+            let keys = shortcuts.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+            KeyRow::new(theme, &keys, false, None)
+        });
         let spacebar = Key::spacebar(theme, caps_on);
         let newline = Key::newline(theme, caps_on);
-        KeyboardRow(Row::center(6.0), None, Some(paginator), None, Some(spacebar), Some(newline))
+        KeyboardRow(Row::center(6.0), None, Some(paginator), globe, shortcuts, Some(spacebar), Some(newline))
+    }
+
+    /// A row of the digit pad; the `last` row ends with backspace.
+    fn pad(theme: &Theme, keys: &[String], digits: &KeyboardLayout, last: bool) -> Self {
+        let backspace = last.then(|| Key::backspace(theme));
+        KeyboardRow(Row::center(6.0), None, None, None, Some(KeyRow::pad(theme, keys, digits)), None, backspace)
     }
 
     fn capslock(&mut self) -> &mut Option<Capslock> {&mut self.1}
@@ -139,13 +225,14 @@ impl KeyboardHeader {
 }
 
 #[derive(Component, Debug, Clone)]
-struct KeyboardIcons(Row, Bin<Stack, Rectangle>, GhostIconButton);
+struct KeyboardIcons(Row, GhostIconButton, GhostIconButton, Bin<Stack, Rectangle>, GhostIconButton);
 impl OnEvent for KeyboardIcons {}
 impl KeyboardIcons {
     fn new(theme: &Theme) -> Self {
         KeyboardIcons(
             Row::new(16.0, Offset::Start, Size::Fit, Padding(12.0, 6.0, 12.0, 6.0)), 
-            // icons.then(|| KeyboardActions(Stack::default(), actions)),
+            GhostIconButton::new(theme, Icons::Copy, |ctx: &mut Context, _: &Theme| ctx.emit(EditEvent::Copy)),
+            GhostIconButton::new(theme, Icons::Paste, |ctx: &mut Context, _: &Theme| ctx.emit(EditEvent::Paste)),
             Bin (
                 Stack(Offset::Center, Offset::Center, Size::Fill, Size::Static(1.0),  Padding::default()), 
                 Rectangle::new(Color::TRANSPARENT, 0.0, None)
@@ -159,36 +246,93 @@ impl KeyboardIcons {
 }
 
 #[derive(Debug, Component, Clone)]
-struct Key(Stack, interactions::Button);
-impl OnEvent for Key {}
+struct Key(Stack, interactions::Button, #[skip] Option<LongPress>);
+impl OnEvent for Key {
+    fn on_event(&mut self, ctx: &mut Context, _sized: &SizedTree, event: Box<dyn Event>) -> Vec<Box<dyn Event>> {
+        if let Some(hold) = &mut self.2 {
+            if let Some(MouseEvent{state, position, ..}) = event.downcast_ref::<MouseEvent>() {
+                match state {
+                    MouseState::Pressed if position.is_some() => {
+                        hold.ticks = Some(0);
+                        hold.held.store(false, Ordering::Relaxed);
+                    },
+                    MouseState::Released => hold.ticks = None,
+                    _ => {}
+                }
+            } else if event.downcast_ref::<TickEvent>().is_some() && let Some(ticks) = &mut hold.ticks {
+                *ticks += 1;
+                if *ticks == LongPress::TICKS {
+                    hold.held.store(true, Ordering::Relaxed);
+                    ctx.trigger_haptic();
+                    ctx.emit(MobileKeyboardEvent::Accents(hold.alternates.clone()));
+                }
+            }
+        }
+        vec![event]
+    }
+}
+
 impl Key {
-    fn character(theme: &Theme, character: &str) -> Self {
-        let default = _Key::character(theme, character, ButtonState::Default);
-        let pressed = _Key::character(theme, character, ButtonState::Pressed);
+    fn character(theme: &Theme, character: &str, alternates: Vec<String>) -> Self {
+        let default = _Key::character(theme, character, ButtonState::Default, 33.0);
+        let pressed = _Key::character(theme, character, ButtonState::Pressed, 33.0);
+        Key::typed(default, pressed, character, alternates)
+    }
+
+    fn digit(theme: &Theme, digit: &str, alternates: Vec<String>) -> Self {
+        let default = _Key::character(theme, digit, ButtonState::Default, f32::MAX);
+        let pressed = _Key::character(theme, digit, ButtonState::Pressed, f32::MAX);
+        Key::typed(default, pressed, digit, alternates)
+    }
+
+    fn typed(default: _Key, pressed: _Key, character: &str, alternates: Vec<String>) -> Self {
         let character = character.to_string();
-        let callback = Box::new(move |ctx: &mut Context| ctx.emit(KeyboardEvent{key: event::Key::Character(character.to_string()), state: KeyboardState::Pressed, modifiers: Modifiers::default()})); // emmit character
-        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false))
+        let held = Arc::new(AtomicBool::new(false));
+        let skip = held.clone();
+        let callback = Box::new(move |ctx: &mut Context| {
+            // A long press already opened the accent popup; releasing it shouldn't type the base letter.
+            if skip.swap(false, Ordering::Relaxed) { return; }
+            ctx.emit(KeyboardEvent{key: event::Key::Character(character.to_string()), state: KeyboardState::Pressed, modifiers: Modifiers::default()})
+        });
+
+        match alternates.is_empty() {
+            true => Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false), None),
+            false => {
+                let button = interactions::Button::new_triggers_on_release(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false);
+                Key(Stack::default(), button, Some(LongPress { alternates, ticks: None, held }))
+            }
+        }
+    }
+
+    fn globe(theme: &Theme) -> Self {
+        let default = _Key::globe(theme, ButtonState::Default);
+        let pressed = _Key::globe(theme, ButtonState::Pressed);
+        let callback = Box::new(move |ctx: &mut Context| {
+            ctx.trigger_haptic();
+            ctx.emit(MobileKeyboardEvent::NextLayout);
+        });
+        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false), None)
     }
 
     fn spacebar(theme: &Theme, caps_on: bool) -> Self {
         let default = _Key::spacebar(theme, caps_on, ButtonState::Default);
         let pressed = _Key::spacebar(theme, caps_on, ButtonState::Pressed);
         let callback = Box::new(move |ctx: &mut Context| ctx.emit(KeyboardEvent{key: event::Key::Named(NamedKey::Space), state: KeyboardState::Pressed, modifiers: Modifiers::default()})); // emmit space
-        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false))
+        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false), None)
     }
 
     fn newline(theme: &Theme, caps_on: bool) -> Self {
         let default = _Key::newline(theme, caps_on, ButtonState::Default);
         let pressed = _Key::newline(theme, caps_on, ButtonState::Pressed);
         let callback = Box::new(move |ctx: &mut Context| ctx.emit(KeyboardEvent{key: event::Key::Named(NamedKey::Enter), state: KeyboardState::Pressed, modifiers: Modifiers::default()})); // emmit newline
-        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false))
+        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false), None)
     }
 
     fn backspace(theme: &Theme) -> Self {
         let default = _Key::backspace(theme, ButtonState::Default);
         let pressed = _Key::backspace(theme, ButtonState::Pressed);
         let callback = Box::new(move |ctx: &mut Context| ctx.emit(KeyboardEvent{key: event::Key::Named(NamedKey::Delete), state: KeyboardState::Pressed, modifiers: Modifiers::default()})); // emmit delete
-        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false))
+        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, Some(pressed), None::<_Key>, None::<_Key>, callback, false), None)
     }
 
     fn capslock(theme: &Theme, state: ButtonState) -> Self {
@@ -198,10 +342,23 @@ impl Key {
             ButtonState::Default => true,
         })));
 
-        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, None::<_Key>, None::<_Key>, None::<_Key>, callback, false))
+        Key(Stack::default(), interactions::Button::new(default, None::<_Key>, None::<_Key>, None::<_Key>, None::<_Key>, callback, false), None)
     }
 }
 
+/// Tracks how long a key with accents has been held.
+#[derive(Debug, Clone)]
+struct LongPress {
+    alternates: Vec<String>,
+    ticks: Option<u32>,
+    held: Arc<AtomicBool>,
+}
+
+impl LongPress {
+    /// About half a second at 60 ticks per second.
+    const TICKS: u32 = 30;
+}
+
 #[derive(Debug, Component, Clone)]
 struct Capslock(Stack, interactions::Selectable);
 impl OnEvent for Capslock {}
@@ -227,6 +384,7 @@ enum _Key {
     Spacebar {layout: Stack, background: Rectangle, text: Text},
     Capslock {layout: Stack, background: Rectangle, icon: Image},
     Backspace {layout: Stack, background: Rectangle, icon: Image},
+    Globe {layout: Stack, background: Rectangle, icon: Image},
     Paginator {layout: Stack, background: Rectangle, content: Box<PaginatorContent>},
     Newline {layout: Stack, background: Rectangle, text: Text,}
 }
@@ -234,9 +392,9 @@ enum _Key {
 impl OnEvent for _Key {}
 
 impl _Key {
-    fn character(theme: &Theme, character: &str, state: ButtonState) -> Self {
+    fn character(theme: &Theme, character: &str, state: ButtonState, max_width: f32) -> Self {
         _Key::Character {
-            layout: Stack(Offset::Center, Offset::End, Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, 33.0)), Size::Static(48.0), Padding(3.0, 6.0, 3.0, 6.0)),
+            layout: Stack(Offset::Center, Offset::End, Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, max_width)), Size::Static(48.0), Padding(3.0, 6.0, 3.0, 6.0)),
             background: Rectangle::new(match state {
                 ButtonState::Default => Color::from_hex("ffffff", 110),
                 ButtonState::Pressed => Color::from_hex("ffffff", 130)
@@ -255,9 +413,9 @@ impl _Key {
                 ButtonState::Default => Color::from_hex("ffffff", 110),
                 ButtonState::Pressed => Color::from_hex("ffffff", 130)
             }, 4.0, None),
-            text: Text::new(theme, match caps_on {
-                true => "SPACE",
-                false => "space",
+            text: Text::new(theme, &match caps_on {
+                true => theme.tr("keyboard-space").to_uppercase(),
+                false => theme.tr("keyboard-space"),
             }, TextSize::Md, TextStyle::Keyboard, Align::Left, None)
         }
     }
@@ -269,9 +427,9 @@ impl _Key {
                 ButtonState::Default => Color::from_hex("ffffff", 110),
                 ButtonState::Pressed => Color::from_hex("ffffff", 130)
             }, 4.0, None),
-            text: Text::new(theme, match caps_on {
-                true => "RETURN",
-                false => "return",
+            text: Text::new(theme, &match caps_on {
+                true => theme.tr("keyboard-return").to_uppercase(),
+                false => theme.tr("keyboard-return"),
             }, TextSize::Md, TextStyle::Keyboard, Align::Left, None)
         }
     }
@@ -300,6 +458,17 @@ impl _Key {
         }
     }
 
+    fn globe(theme: &Theme, state: ButtonState) -> Self {
+        _Key::Globe {
+            layout: Stack(Offset::Center, Offset::Center, Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, 42.0)), Size::Static(48.0), Padding(3.0, 6.0, 3.0, 6.0)),
+            background: Rectangle::new(match state {
+                ButtonState::Default => Color::from_hex("ffffff", 110),
+                ButtonState::Pressed => Color::from_hex("ffffff", 130)
+            }, 4.0, None),
+            icon: Icon::new(theme, Icons::Explore, Some(Color::WHITE), 28.0),
+        }
+    }
+
     fn paginator(theme: &Theme, page: usize) -> Self {
         _Key::Paginator {
             layout: Stack(Offset::Center, Offset::Center, Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, 92.0)), Size::Static(48.0), Padding(3.0, 6.0, 3.0, 6.0)),
@@ -402,34 +571,74 @@ impl std::fmt::Debug for _Paginator {
     }
 }
 
-fn top_keys(page: &usize) -> Vec<&str> {
+/// Number and symbol pages, shared by every layout.
+const SYMBOLS: [[&str; 3]; 2] = [
+    ["1 2 3 4 5 6 7 8 9 0", "/ \\ \" ' ~ . , ? !", "@ | ` ˚ € £ ¥"],
+    ["[ ] { } ( ) < > + =", "- : ; # % $ & ^ *", "™ © • ¶ § ° …"],
+];
+
+fn keys(layout: &KeyboardLayout, page: usize, row: usize) -> Vec<String> {
     match page {
-        0 => vec!["q", "w", "e", "r", "t", "y", "u", "i", "o", "p"],
-        1 => vec!["1", "2", "3", "4", "5", "6", "7", "8", "9", "0"],
-        _ => vec!["[", "]", "{", "}", "(", ")", "<", ">", "+", "="]
+        0 => layout.rows[row].clone(),
+        _ => SYMBOLS[(page - 1).min(1)][row].split_whitespace().map(str::to_string).collect(),
     }
 }
 
-fn mid_keys(page: &usize) -> Vec<&str> {
-    match page {
-        0 => vec!["a", "s", "d", "f", "g", "h", "j", "k", "l"],
-        1 => vec!["/", "\\", "\"", "'", "~", ".", ",", "?", "!"],
-        _ => vec!["-", ":", ";", "#", "%", "$", "&", "^", "*",]
-    }  
+/// Extra keys beside the spacebar for fields that type addresses.
+fn shortcuts(input_type: InputType) -> &'static [&'static str] {
+    match input_type {
+        InputType::Email => &["@", ".com"],
+        InputType::Url => &["/", ".com"],
+        _ => &[],
+    }
 }
 
-fn bot_keys(page: &usize) -> Vec<&str> {
-    match page {
-        0 => vec!["z", "x", "c", "v", "b", "n", "m"],
-        1 => vec!["@", "|", "`", "˚", "€", "£", "¥"],
-        _ => vec!["™", "©", "•", "¶", "€", "£", "¥"]
-    }  
+/// The digit pad replacing the letters for numeric fields, and its last row.
+fn pad(input_type: InputType, decimal: char) -> Option<(KeyboardLayout, Vec<String>)> {
+    let digits = KeyboardLayout::new("123", ["1 2 3", "4 5 6", "7 8 9"]);
+    let last = match input_type {
+        InputType::Number => vec!["0".to_string()],
+        InputType::Decimal => vec![decimal.to_string(), "0".to_string()],
+        InputType::Phone => return Some((digits.accents("0", "+"), vec!["*".to_string(), "0".to_string(), "#".to_string()])),
+        _ => return None,
+    };
+    Some((digits, last))
+}
+
+/// The kind of value a field expects, so the [`MobileKeyboard`] can show the keys it needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputType {
+    #[default]
+    Text,
+    /// Digits only.
+    Number,
+    /// Digits and the locale's decimal separator.
+    Decimal,
+    /// Adds "@" and ".com" keys.
+    Email,
+    /// Adds "/" and ".com" keys.
+    Url,
+    /// A dialer pad with "*", "#" and "+" (long-press "0").
+    Phone,
+    Password,
+}
+
+/// Sent by a focused field so the [`MobileKeyboard`] switches to the keys for its [`InputType`].
+#[derive(Debug, Clone, Copy)]
+pub struct KeyboardType(pub InputType);
+
+impl Event for KeyboardType {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: &[Area]) -> Vec<Option<Box<dyn Event>>> {
+        children.iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
 }
 
 #[derive(Debug, Clone)]
 enum MobileKeyboardEvent {
     Capslock(bool),
     Paginator(usize),
+    NextLayout,
+    Accents(Vec<String>),
 }
 
 impl Event for MobileKeyboardEvent {
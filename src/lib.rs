@@ -4,11 +4,17 @@ use prism::layout::Stack;
 
 use std::hash::Hash;
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 pub mod components;
 pub mod interface;
 
 pub mod theme;
+pub mod accessibility;
+pub mod locale;
+
+#[cfg(feature = "testing")]
+pub mod testing;
 use theme::Theme;
 
 pub use prism::*;
@@ -52,3 +58,20 @@ impl std::fmt::Debug for dyn Callback {
         write!(f, "Clonable Closure")
     }
 }
+
+/// A closure shared by a component and the parts it rebuilds, so every copy calls the same one.
+pub struct Shared<F: ?Sized>(pub(crate) Arc<Mutex<F>>);
+
+impl<F: ?Sized> Shared<F> {
+    pub fn lock(&self) -> MutexGuard<'_, F> {self.0.lock().unwrap()}
+}
+
+impl<F: ?Sized> Clone for Shared<F> {
+    fn clone(&self) -> Self {Shared(self.0.clone())}
+}
+
+impl<F: ?Sized> std::fmt::Debug for Shared<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Shared Closure")
+    }
+}
@@ -0,0 +1,370 @@
+//! Message catalogs and text direction.
+//!
+//! A [`Locale`] lives on the [`Theme`](crate::theme::Theme), so every component
+//! that builds user-facing text or a horizontal layout can look it up:
+//!
+//! ```rust
+//! let theme = Theme::default().with_locale(Locale::new("ar"));
+//! let label = theme.tr("continue");
+//! let price = theme.locale().number_format().currency(1234.56);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use prism::canvas::Align;
+use prism::layout::{Area, Layout, Row, SizeRequest};
+
+use crate::interface::layouts::KeyboardLayout;
+
+const BUILT_IN: [(&str, &str); 3] = [
+    ("en", include_str!("../resources/locales/en.ftl")),
+    ("ar", include_str!("../resources/locales/ar.ftl")),
+    ("he", include_str!("../resources/locales/he.ftl")),
+];
+
+const RTL_LANGUAGES: [&str; 8] = ["ar", "he", "fa", "ur", "yi", "ps", "sd", "ug"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDirection { #[default] Ltr, Rtl }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurrencyPosition { #[default] Prefix, Suffix }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateOrder { #[default] DayMonth, MonthDay }
+
+/// How numbers, amounts and dates are written for a locale.
+///
+/// The default matches pelican_ui's original hard-coded formatting: `$1,234.56` and `DD-MM`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberFormat {
+    pub currency: String,
+    pub position: CurrencyPosition,
+    /// Whether the symbol is separated from the amount by a space, as in `1.234,56 €`.
+    pub spaced: bool,
+    pub grouping: Option<char>,
+    pub decimal: char,
+    pub decimals: usize,
+    pub date_order: DateOrder,
+    pub date_separator: char,
+    /// Whether times are shown on a 24-hour clock rather than with AM/PM.
+    pub twenty_four_hour: bool,
+}
+
+impl NumberFormat {
+    /// The conventional format for a language tag such as `"de-DE"` or `"ja"`.
+    pub fn for_tag(tag: &str) -> Self {
+        let mut parts = tag.split(['-', '_']);
+        let language = parts.next().unwrap_or(tag);
+        let region = parts.next().unwrap_or("").to_ascii_uppercase();
+        let euro = NumberFormat {
+            currency: "€".to_string(), position: CurrencyPosition::Suffix, spaced: true,
+            grouping: Some('.'), decimal: ',', date_separator: '.', twenty_four_hour: true, ..Self::default()
+        };
+
+        match (language, region.as_str()) {
+            ("en", "GB") => NumberFormat { currency: "£".to_string(), date_separator: '/', twenty_four_hour: true, ..Self::default() },
+            ("en", "IN") | ("hi", _) => NumberFormat { currency: "₹".to_string(), date_separator: '/', ..Self::default() },
+            ("fr", _) => NumberFormat { grouping: Some(' '), date_separator: '/', ..euro },
+            ("pt", "BR") => NumberFormat { currency: "R$".to_string(), position: CurrencyPosition::Prefix, date_separator: '/', ..euro },
+            ("de" | "es" | "it" | "nl" | "pt", _) => euro,
+            ("ja", _) => NumberFormat { currency: "¥".to_string(), decimals: 0, date_order: DateOrder::MonthDay, date_separator: '/', twenty_four_hour: true, ..Self::default() },
+            ("zh", _) => NumberFormat { currency: "¥".to_string(), date_order: DateOrder::MonthDay, date_separator: '/', twenty_four_hour: true, ..Self::default() },
+            _ => Self::default(),
+        }
+    }
+
+    /// Groups the integer digits and pads the fraction to [`decimals`](Self::decimals),
+    /// without a currency symbol: `1234.5` becomes `1.234,50`.
+    pub fn number(&self, amount: f64) -> String {
+        let fixed = format!("{:.*}", self.decimals, amount.abs());
+        let (whole, fraction) = fixed.split_once('.').unwrap_or((fixed.as_str(), ""));
+        let mut out = self.group(whole);
+        if !fraction.is_empty() {
+            out.push(self.decimal);
+            out.push_str(fraction);
+        }
+        match amount < 0.0 && fixed.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            true => format!("-{out}"),
+            false => out,
+        }
+    }
+
+    pub fn currency(&self, amount: f64) -> String {
+        let number = self.number(amount);
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number.as_str()),
+        };
+        let space = if self.spaced { " " } else { "" };
+        match self.position {
+            CurrencyPosition::Prefix => format!("{sign}{}{space}{number}", self.currency),
+            CurrencyPosition::Suffix => format!("{sign}{number}{space}{}", self.currency),
+        }
+    }
+
+    /// Inserts the grouping separator every three digits from the right.
+    pub fn group(&self, digits: &str) -> String {
+        let Some(separator) = self.grouping else { return digits.to_string() };
+        let reversed = digits.chars().rev().collect::<Vec<char>>();
+        reversed.chunks(3).map(|c| c.iter().collect::<String>()).collect::<Vec<_>>()
+            .join(separator.to_string().as_str()).chars().rev().collect()
+    }
+
+    /// Turns text written in this format back into a plain machine number,
+    /// e.g. `1.234,56 €` into `1234.56`.
+    pub fn normalize(&self, text: &str) -> String {
+        let text = text.trim();
+        let (negative, text) = match text.strip_prefix('-') {
+            Some(text) => (true, text),
+            None => (false, text),
+        };
+        let body = match self.position {
+            CurrencyPosition::Prefix => text.strip_prefix(self.currency.as_str()),
+            CurrencyPosition::Suffix => text.strip_suffix(self.currency.as_str()),
+        }.unwrap_or(text);
+
+        let mut out = if negative { String::from("-") } else { String::new() };
+        for ch in body.chars() {
+            if ch.is_ascii_digit() { out.push(ch); }
+            else if ch == self.decimal { out.push('.'); }
+        }
+        out
+    }
+
+    /// The day and month placeholders in display order, e.g. `['M', 'M', 'D', 'D']`.
+    pub fn date_fields(&self) -> [char; 4] {
+        match self.date_order {
+            DateOrder::DayMonth => ['D', 'D', 'M', 'M'],
+            DateOrder::MonthDay => ['M', 'M', 'D', 'D'],
+        }
+    }
+
+    /// Reorders four day/month digits written in this format into `MM-DD`.
+    pub fn normalize_date(&self, text: &str) -> String {
+        let digits = text.chars().filter(|c| c.is_ascii_digit()).collect::<Vec<char>>();
+        if digits.len() != 4 { return text.to_string(); }
+        let (first, second) = (digits[..2].iter().collect::<String>(), digits[2..].iter().collect::<String>());
+        match self.date_order {
+            DateOrder::DayMonth => format!("{second}-{first}"),
+            DateOrder::MonthDay => format!("{first}-{second}"),
+        }
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            currency: "$".to_string(),
+            position: CurrencyPosition::Prefix,
+            spaced: false,
+            grouping: Some(','),
+            decimal: '.',
+            decimals: 2,
+            date_order: DateOrder::DayMonth,
+            date_separator: '-',
+            twenty_four_hour: false,
+        }
+    }
+}
+
+/// A flat key/value message catalog in a subset of Fluent syntax:
+/// `key = value` lines, `#` comments, and `{ $name }` placeables.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog(HashMap<String, String>);
+
+impl Catalog {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut messages = HashMap::new();
+        for (number, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            let (key, value) = line.split_once('=').ok_or(format!("Line {}: expected 'key = value'", number + 1))?;
+            messages.insert(key.trim().to_string(), value.trim().to_string());
+        }
+        Ok(Catalog(messages))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {self.0.get(key).map(|s| s.as_str())}
+    pub fn insert(&mut self, key: &str, message: &str) {self.0.insert(key.to_string(), message.to_string());}
+}
+
+/// A language tag plus the catalog its strings are looked up in.
+///
+/// Missing keys fall back to the built-in English catalog, then to the key itself.
+#[derive(Debug, Clone)]
+pub struct Locale {
+    tag: String,
+    catalog: Arc<Catalog>,
+    fallback: Arc<Catalog>,
+    format: NumberFormat,
+    keyboards: Option<Vec<KeyboardLayout>>,
+}
+
+impl Locale {
+    /// A locale using pelican_ui's built-in strings for `tag` (e.g. `"he-IL"`), if any.
+    pub fn new(tag: &str) -> Self {
+        let catalog = BUILT_IN.iter().find(|(language, _)| *language == Self::language_of(tag))
+            .map(|(_, source)| Catalog::parse(source).unwrap_or_default())
+            .unwrap_or_default();
+        Self::with_catalog(tag, catalog)
+    }
+
+    pub fn with_catalog(tag: &str, catalog: Catalog) -> Self {
+        let fallback = Catalog::parse(BUILT_IN[0].1).unwrap_or_default();
+        Locale { tag: tag.to_string(), catalog: Arc::new(catalog), fallback: Arc::new(fallback), format: NumberFormat::for_tag(tag), keyboards: None }
+    }
+
+    /// Overrides the number, currency and date conventions derived from the tag.
+    pub fn with_number_format(mut self, format: NumberFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Parses `source` and layers it over the built-in strings for `tag`.
+    pub fn from_source(tag: &str, source: &str) -> Result<Self, String> {
+        let mut locale = Self::new(tag);
+        let mut catalog = (*locale.catalog).clone();
+        Catalog::parse(source)?.0.into_iter().for_each(|(key, message)| { catalog.0.insert(key, message); });
+        locale.catalog = Arc::new(catalog);
+        Ok(locale)
+    }
+
+    pub fn tag(&self) -> &str {&self.tag}
+    pub fn language(&self) -> &str {Self::language_of(&self.tag)}
+
+    pub fn direction(&self) -> TextDirection {
+        match RTL_LANGUAGES.contains(&self.language()) {
+            true => TextDirection::Rtl,
+            false => TextDirection::Ltr,
+        }
+    }
+
+    pub fn number_format(&self) -> &NumberFormat {&self.format}
+
+    /// Replaces the on-screen keyboard layouts; the globe key cycles through them in order.
+    pub fn with_keyboards(mut self, layouts: Vec<KeyboardLayout>) -> Self {
+        self.keyboards = (!layouts.is_empty()).then_some(layouts);
+        self
+    }
+
+    pub fn keyboards(&self) -> Vec<KeyboardLayout> {
+        self.keyboards.clone().unwrap_or_else(|| KeyboardLayout::for_language(self.language()))
+    }
+
+    pub fn is_rtl(&self) -> bool {self.direction() == TextDirection::Rtl}
+
+    /// The alignment of text at the start of a line: left, or right for RTL scripts.
+    pub fn start(&self) -> Align {if self.is_rtl() { Align::Right } else { Align::Left }}
+
+    /// The alignment of text at the end of a line: right, or left for RTL scripts.
+    pub fn end(&self) -> Align {if self.is_rtl() { Align::Left } else { Align::Right }}
+
+    pub fn translate(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let message = self.catalog.get(key).or(self.fallback.get(key)).unwrap_or(key);
+        args.iter().fold(message.to_string(), |message, (name, value)| {
+            message.replace(&format!("{{ ${name} }}"), value).replace(&format!("{{${name}}}"), value)
+        })
+    }
+
+    fn language_of(tag: &str) -> &str {tag.split(['-', '_']).next().unwrap_or(tag)}
+}
+
+impl Default for Locale {
+    fn default() -> Self {Locale::new("en-US")}
+}
+
+/// A layout that mirrors its inner layout horizontally for right-to-left locales,
+/// so the first child of a [`Row`] is placed at the right edge.
+#[derive(Debug, Clone)]
+pub struct Directional<L: Layout = Row>(pub L, pub TextDirection);
+
+impl<L: Layout> Directional<L> {
+    pub fn new(layout: L, locale: &Locale) -> Self {Directional(layout, locale.direction())}
+}
+
+impl<L: Layout> Layout for Directional<L> {
+    fn request_size(&self, children: Vec<SizeRequest>) -> SizeRequest {
+        self.0.request_size(children)
+    }
+
+    fn build(&self, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let areas = self.0.build(size, children);
+        match self.1 {
+            TextDirection::Ltr => areas,
+            TextDirection::Rtl => areas.into_iter().map(|area| Area {
+                offset: (size.0 - area.offset.0 - area.size.0, area.offset.1),
+                size: area.size,
+            }).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalogs_skip_comments_and_blank_lines() {
+        let catalog = Catalog::parse("# Greetings\n\nhello = Hello, { $name }!\n").unwrap();
+        assert_eq!(catalog.get("hello"), Some("Hello, { $name }!"));
+        assert!(Catalog::parse("no separator").is_err());
+    }
+
+    #[test]
+    fn translations_fall_back_to_english_then_the_key() {
+        let hebrew = Locale::new("he-IL");
+        assert_eq!(hebrew.translate("done", &[]), "סיום");
+        assert_eq!(hebrew.translate("amount-minimum", &[("min", "5")]), "המינימום הוא 5");
+
+        let custom = Locale::from_source("de-DE", "done = Fertig").unwrap();
+        assert_eq!(custom.translate("done", &[]), "Fertig");
+        assert_eq!(custom.translate("continue", &[]), "Continue");
+        assert_eq!(custom.translate("missing-key", &[]), "missing-key");
+    }
+
+    #[test]
+    fn numbers_follow_the_locale() {
+        let (english, german, japanese) = (NumberFormat::for_tag("en-US"), NumberFormat::for_tag("de-DE"), NumberFormat::for_tag("ja"));
+        assert_eq!(english.number(1234567.891), "1,234,567.89");
+        assert_eq!(german.number(1234.5), "1.234,50");
+        assert_eq!(japanese.number(1234.6), "1,235");
+        assert_eq!(english.number(-0.001), "0.00");
+        assert_eq!(english.number(-12.5), "-12.50");
+    }
+
+    #[test]
+    fn currency_symbols_are_placed_and_spaced() {
+        assert_eq!(NumberFormat::for_tag("en-US").currency(-1234.5), "-$1,234.50");
+        assert_eq!(NumberFormat::for_tag("de-DE").currency(1234.5), "1.234,50 €");
+        assert_eq!(NumberFormat::for_tag("fr-FR").currency(1234.5), "1 234,50 €");
+        assert_eq!(NumberFormat::for_tag("pt-BR").currency(3.0), "R$ 3,00");
+    }
+
+    #[test]
+    fn normalize_reverses_formatting() {
+        let german = NumberFormat::for_tag("de-DE");
+        assert_eq!(german.normalize(&german.currency(1234.56)), "1234.56");
+        assert_eq!(german.normalize("-1.234,56 €"), "-1234.56");
+        assert_eq!(NumberFormat::default().normalize("$1,000.05"), "1000.05");
+    }
+
+    #[test]
+    fn dates_are_reordered_to_month_day() {
+        let (day_month, month_day) = (NumberFormat::for_tag("en-GB"), NumberFormat::for_tag("ja"));
+        assert_eq!(day_month.date_fields(), ['D', 'D', 'M', 'M']);
+        assert_eq!(day_month.normalize_date("25/12"), "12-25");
+        assert_eq!(month_day.normalize_date("12/25"), "12-25");
+        assert_eq!(month_day.normalize_date("1/2"), "1/2");
+    }
+
+    #[test]
+    fn rtl_languages_mirror_alignment() {
+        let (arabic, english) = (Locale::new("ar_EG"), Locale::default());
+        assert!(arabic.is_rtl() && !english.is_rtl());
+        assert_eq!(arabic.direction(), TextDirection::Rtl);
+        assert!(matches!(arabic.start(), Align::Right) && matches!(arabic.end(), Align::Left));
+        assert!(matches!(english.start(), Align::Left) && matches!(english.end(), Align::Right));
+    }
+}
@@ -0,0 +1,161 @@
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use prism::Context;
+use prism::event::{Event, TickEvent, MouseEvent, MouseState, MouseButton, KeyboardEvent, KeyboardState, Key, NamedKey, Modifiers};
+use prism::drawable::{Drawable, SizedTree};
+
+use crate::theme::Theme;
+use crate::components::TextInput;
+use crate::interface::general::Interface;
+use crate::interface::navigation::RootInfo;
+
+type Capture = Box<dyn Fn(&dyn Event) -> Option<Box<dyn Any>>>;
+
+/// Drives an [`Interface`] headlessly: injects input, pumps emitted events back
+/// through the tree and lets tests query the resulting components.
+///
+/// ```rust
+/// let mut driver = Driver::new((390.0, 844.0), &Theme::default(), |ctx, theme| vec![
+///     RootInfo::icon(Icons::Home, "Home", BuyTicketFlow::new(ctx, theme)),
+/// ]);
+/// driver.watch::<NavigationEvent>();
+///
+/// driver.text_input("Name").unwrap().inner.focus();
+/// driver.type_text("Ada");
+/// driver.key(NamedKey::Enter);
+///
+/// assert_eq!(driver.find::<NumericalInput>().unwrap().value(), "0");
+/// assert!(driver.emitted::<NavigationEvent>().iter().any(|e| matches!(e, NavigationEvent::Push(..))));
+/// ```
+pub struct Driver {
+    ctx: Context,
+    root: Interface,
+    size: (f32, f32),
+    sized: SizedTree,
+    log: Arc<Mutex<Vec<String>>>,
+    watchers: Vec<Capture>,
+    captured: Vec<Box<dyn Any>>,
+}
+
+impl Driver {
+    /// The most events a single input may cascade into before the driver assumes a loop.
+    const MAX_EVENTS: usize = 10_000;
+
+    pub fn new(size: (f32, f32), theme: &Theme, roots: impl FnOnce(&mut Context, &Theme) -> Vec<RootInfo>) -> Self {
+        let mut ctx = Context::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let recorder = log.clone();
+        let on_event = move |_ctx: &mut Context, event: Box<dyn Event>| {
+            recorder.lock().unwrap().push(format!("{event:?}"));
+            vec![event]
+        };
+
+        let roots = roots(&mut ctx, theme);
+        let root = Interface::new(&mut ctx, theme, roots, Box::new(on_event));
+        let sized = root.build(size, root.request_size());
+
+        let mut driver = Driver { ctx, root, size, sized, log, watchers: Vec::new(), captured: Vec::new() };
+        driver.tick();
+        driver
+    }
+
+    /// Captures every emitted event of type `T` so it can be asserted with [`Driver::emitted`].
+    pub fn watch<T: Event + Clone + 'static>(&mut self) {
+        self.watchers.push(Box::new(|event| event.as_any().downcast_ref::<T>().map(|e| Box::new(e.clone()) as Box<dyn Any>)));
+    }
+
+    pub fn emitted<T: 'static>(&self) -> Vec<&T> {
+        self.captured.iter().filter_map(|e| e.downcast_ref::<T>()).collect()
+    }
+
+    /// The `Debug` output of every event that reached the `Interface`, in order.
+    pub fn log(&self) -> Vec<String> {self.log.lock().unwrap().clone()}
+
+    pub fn clear(&mut self) {
+        self.log.lock().unwrap().clear();
+        self.captured.clear();
+    }
+
+    /// Dispatches `event` from the root and keeps dispatching whatever the
+    /// components emit in response until the tree settles.
+    pub fn send(&mut self, event: impl Event + 'static) {
+        let mut queue: Vec<Box<dyn Event>> = vec![Box::new(event)];
+        let mut handled = 0;
+
+        while !queue.is_empty() {
+            for event in std::mem::take(&mut queue) {
+                handled += 1;
+                assert!(handled < Self::MAX_EVENTS, "event loop did not settle after {} events", Self::MAX_EVENTS);
+
+                self.captured.extend(self.watchers.iter().filter_map(|watch| (watch)(event.as_ref())));
+                self.root.event(&mut self.ctx, &self.sized, event);
+            }
+            queue.extend(self.ctx.drain_events());
+        }
+
+        self.sized = self.root.build(self.size, self.root.request_size());
+    }
+
+    pub fn tick(&mut self) {self.send(TickEvent)}
+
+    pub fn ticks(&mut self, count: usize) {(0..count).for_each(|_| self.tick())}
+
+    /// Presses and releases the primary mouse button at `position`, then ticks.
+    pub fn click(&mut self, position: (f32, f32)) {
+        for state in [MouseState::Pressed, MouseState::Released] {
+            self.send(MouseEvent{state, position: Some(position), button: Some(MouseButton::Left)});
+        }
+        self.tick();
+    }
+
+    pub fn scroll(&mut self, position: (f32, f32), delta: (f32, f32)) {
+        self.send(MouseEvent{state: MouseState::Scroll(delta.0, delta.1), position: Some(position), button: None});
+        self.tick();
+    }
+
+    pub fn key(&mut self, key: NamedKey) {
+        self.send(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(key), modifiers: Modifiers::default()});
+        self.tick();
+    }
+
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            let key = match c {
+                ' ' => Key::Named(NamedKey::Space),
+                '\n' => Key::Named(NamedKey::Enter),
+                c => Key::Character(c.to_string()),
+            };
+            self.send(KeyboardEvent{state: KeyboardState::Pressed, key, modifiers: Modifiers::default()});
+        }
+        self.tick();
+    }
+
+    pub fn context(&mut self) -> &mut Context {&mut self.ctx}
+    pub fn interface(&mut self) -> &mut Interface {&mut self.root}
+
+    /// The first component of type `T` in the tree, depth first.
+    pub fn find<T: 'static>(&mut self) -> Option<&mut T> {
+        self.find_all::<T>().into_iter().next()
+    }
+
+    pub fn find_all<T: 'static>(&mut self) -> Vec<&mut T> {
+        let mut found = Vec::new();
+        collect(&mut self.root, &mut found);
+        found
+    }
+
+    /// The [`TextInput`] whose label reads `label`.
+    pub fn text_input(&mut self, label: &str) -> Option<&mut TextInput> {
+        self.find_all::<TextInput>().into_iter().find(|input| input.label().as_deref() == Some(label))
+    }
+}
+
+fn collect<'a, T: 'static>(drawable: &'a mut dyn Drawable, found: &mut Vec<&'a mut T>) {
+    if drawable.as_any().is::<T>() {
+        found.extend(drawable.as_any_mut().downcast_mut::<T>());
+        return;
+    }
+    drawable.children_mut().into_iter().for_each(|child| collect(child, found));
+}
@@ -0,0 +1,14 @@
+//! Headless helpers for testing pelican_ui components without a GPU or an event loop.
+//!
+//! Enabled with the `testing` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! pelican_ui = { version = "2", features = ["testing"] }
+//! ```
+
+mod snapshot;
+pub use snapshot::*;
+
+mod driver;
+pub use driver::Driver;
@@ -0,0 +1,206 @@
+use std::path::PathBuf;
+
+use image::{Rgba, RgbaImage};
+
+use prism::canvas::{Area as CanvasArea, Item as CanvasItem, Color as CanvasColor, Image, Shape, ShapeType, Text};
+use prism::drawable::{Drawable, Rect};
+
+use crate::theme::Color;
+
+/// Set this environment variable to rewrite golden images instead of comparing against them.
+pub const UPDATE_SNAPSHOTS: &str = "PELICAN_UPDATE_SNAPSHOTS";
+
+/// Lays out and rasterizes a component into an in-memory RGBA buffer and
+/// compares it against a golden PNG.
+///
+/// The rasterizer is intentionally simple: shapes and images are drawn exactly,
+/// rotation is ignored and text is drawn as a translucent block covering its
+/// measured bounds, so snapshots catch layout and color regressions without
+/// depending on the platform's font rendering.
+///
+/// ```rust
+/// let theme = Theme::default();
+/// let item = ListItem::new(&theme, ...);
+/// Snapshot::new(390, 120).assert("list_item_default", &item);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    width: u32,
+    height: u32,
+    background: Color,
+    tolerance: u8,
+    max_mismatch: f32,
+    directory: PathBuf,
+}
+
+impl Snapshot {
+    pub fn new(width: u32, height: u32) -> Self {
+        let root = std::env::var("CARGO_MANIFEST_DIR").map(PathBuf::from).unwrap_or_default();
+        Snapshot { width, height, background: Color::BLACK, tolerance: 2, max_mismatch: 0.001, directory: root.join("tests").join("snapshots") }
+    }
+
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// `channel` is the largest per-channel difference a pixel may have and still
+    /// match; `mismatch` is the fraction of pixels allowed to differ.
+    pub fn tolerance(mut self, channel: u8, mismatch: f32) -> Self {
+        self.tolerance = channel;
+        self.max_mismatch = mismatch;
+        self
+    }
+
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = directory.into();
+        self
+    }
+
+    pub fn render(&self, drawable: &impl Drawable) -> RgbaImage {
+        let size = (self.width as f32, self.height as f32);
+        let sized = drawable.build(size, drawable.request_size());
+        let items = drawable.draw(&sized, (0.0, 0.0), Rect(0.0, 0.0, size.0, size.1));
+
+        let mut raster = Raster(RgbaImage::from_pixel(self.width, self.height, Rgba([self.background.0, self.background.1, self.background.2, 255])));
+        items.into_iter().for_each(|(area, item)| raster.draw(area, item));
+        raster.0
+    }
+
+    /// Renders `drawable` and compares it with `<directory>/<name>.png`.
+    ///
+    /// Goldens are only written when `PELICAN_UPDATE_SNAPSHOTS` is set; a missing
+    /// golden is an error, so a renamed snapshot can't pass unnoticed.
+    pub fn check(&self, name: &str, drawable: &impl Drawable) -> Result<(), String> {
+        let image = self.render(drawable);
+        let path = self.directory.join(format!("{name}.png"));
+
+        if std::env::var_os(UPDATE_SNAPSHOTS).is_some() {
+            std::fs::create_dir_all(&self.directory).map_err(|e| e.to_string())?;
+            return image.save(&path).map_err(|e| e.to_string());
+        }
+
+        if !path.exists() {
+            return Err(format!("no golden for '{name}'"));
+        }
+
+        let golden = image::open(&path).map_err(|e| format!("{}: {e}", path.display()))?.to_rgba8();
+        self.compare(&image, &golden).map_err(|error| {
+            let actual = self.directory.join(format!("{name}.actual.png"));
+            let _ = image.save(&actual);
+            format!("Snapshot '{name}' {error}; wrote {}", actual.display())
+        })
+    }
+
+    pub fn assert(&self, name: &str, drawable: &impl Drawable) {
+        if let Err(error) = self.check(name, drawable) { panic!("{error}"); }
+    }
+
+    pub fn compare(&self, image: &RgbaImage, golden: &RgbaImage) -> Result<(), String> {
+        if image.dimensions() != golden.dimensions() {
+            return Err(format!("is {:?} but the golden is {:?}", image.dimensions(), golden.dimensions()));
+        }
+
+        let differs = |a: &Rgba<u8>, b: &Rgba<u8>| a.0.iter().zip(b.0.iter()).any(|(a, b)| a.abs_diff(*b) > self.tolerance);
+        let mismatched = image.pixels().zip(golden.pixels()).filter(|(a, b)| differs(a, b)).count();
+        let ratio = mismatched as f32 / (image.width() * image.height()).max(1) as f32;
+
+        match ratio > self.max_mismatch {
+            true => Err(format!("differs in {mismatched} pixels ({:.2}%)", ratio * 100.0)),
+            false => Ok(()),
+        }
+    }
+}
+
+struct Raster(RgbaImage);
+
+impl Raster {
+    fn draw(&mut self, area: CanvasArea, item: CanvasItem) {
+        let clip = area.bounds.map(|b| (b.0, b.1, b.0 + b.2, b.1 + b.3));
+        match item {
+            CanvasItem::Shape(Shape{shape, color}) => self.fill(area.offset, clip, shape, |_, _| color),
+            CanvasItem::Image(Image{shape, image, color}) => {
+                let (w, h) = Self::size(&shape);
+                self.fill(area.offset, clip, shape, |u, v| {
+                    let x = ((u / w) * image.width() as f32).clamp(0.0, image.width() as f32 - 1.0) as u32;
+                    let y = ((v / h) * image.height() as f32).clamp(0.0, image.height() as f32 - 1.0) as u32;
+                    let pixel = image.get_pixel(x, y).0;
+                    match color {
+                        Some(tint) => CanvasColor(tint.0, tint.1, tint.2, ((tint.3 as u16 * pixel[3] as u16) / 255) as u8),
+                        None => CanvasColor(pixel[0], pixel[1], pixel[2], pixel[3]),
+                    }
+                })
+            },
+            CanvasItem::Text(text) => self.text(area.offset, clip, &text),
+        }
+    }
+
+    fn size(shape: &ShapeType) -> (f32, f32) {
+        match shape {
+            ShapeType::RoundedRectangle(_, size, _, _) | ShapeType::Rectangle(_, size, _) | ShapeType::Ellipse(_, size, _) => *size,
+        }
+    }
+
+    fn text(&mut self, offset: (f32, f32), clip: Option<(f32, f32, f32, f32)>, text: &Text) {
+        let Some(span) = text.spans.first() else { return };
+        let (w, h) = text.size();
+        let color = CanvasColor(span.color.0, span.color.1, span.color.2, (span.color.3 as u16 * 96 / 255) as u8);
+        self.fill(offset, clip, ShapeType::Rectangle(0.0, (w, h), 0.0), |_, _| color);
+    }
+
+    fn fill(&mut self, offset: (f32, f32), clip: Option<(f32, f32, f32, f32)>, shape: ShapeType, color: impl Fn(f32, f32) -> CanvasColor) {
+        let (w, h) = Self::size(&shape);
+        let (mut x0, mut y0, mut x1, mut y1) = (offset.0, offset.1, offset.0 + w, offset.1 + h);
+        if let Some((cx0, cy0, cx1, cy1)) = clip {
+            (x0, y0, x1, y1) = (x0.max(cx0), y0.max(cy0), x1.min(cx1), y1.min(cy1));
+        }
+
+        let (iw, ih) = (self.0.width() as f32, self.0.height() as f32);
+        let (x0, y0) = (x0.clamp(0.0, iw) as u32, y0.clamp(0.0, ih) as u32);
+        let (x1, y1) = (x1.ceil().clamp(0.0, iw) as u32, y1.ceil().clamp(0.0, ih) as u32);
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let (u, v) = (x as f32 + 0.5 - offset.0, y as f32 + 0.5 - offset.1);
+                if Self::covers(&shape, u, v) { self.blend(x, y, color(u, v)); }
+            }
+        }
+    }
+
+    /// Whether the local point `(u, v)` lies inside the shape; a non-zero
+    /// stroke width only covers the outline band.
+    fn covers(shape: &ShapeType, u: f32, v: f32) -> bool {
+        let inside = |inset: f32| -> bool {
+            match shape {
+                ShapeType::Rectangle(_, (w, h), _) => u >= inset && v >= inset && u <= w - inset && v <= h - inset,
+                ShapeType::RoundedRectangle(_, (w, h), _, r) => {
+                    let r = (r - inset).max(0.0).min((w.min(*h) / 2.0) - inset);
+                    let (hw, hh) = (w / 2.0 - inset, h / 2.0 - inset);
+                    let (dx, dy) = ((u - w / 2.0).abs() - (hw - r), (v - h / 2.0).abs() - (hh - r));
+                    let outside = (dx.max(0.0).powi(2) + dy.max(0.0).powi(2)).sqrt();
+                    outside + dx.max(dy).min(0.0) <= r
+                },
+                ShapeType::Ellipse(_, (w, h), _) => {
+                    let (rx, ry) = (w / 2.0 - inset, h / 2.0 - inset);
+                    rx > 0.0 && ry > 0.0 && ((u - w / 2.0) / rx).powi(2) + ((v - h / 2.0) / ry).powi(2) <= 1.0
+                },
+            }
+        };
+
+        let stroke = match shape {
+            ShapeType::RoundedRectangle(s, ..) | ShapeType::Rectangle(s, ..) | ShapeType::Ellipse(s, ..) => *s,
+        };
+
+        match stroke > 0.0 {
+            true => inside(0.0) && !inside(stroke),
+            false => inside(0.0),
+        }
+    }
+
+    fn blend(&mut self, x: u32, y: u32, color: CanvasColor) {
+        let pixel = self.0.get_pixel_mut(x, y);
+        let alpha = color.3 as f32 / 255.0;
+        let mix = |src: u8, dst: u8| (src as f32 * alpha + dst as f32 * (1.0 - alpha)).round() as u8;
+        *pixel = Rgba([mix(color.0, pixel[0]), mix(color.1, pixel[1]), mix(color.2, pixel[2]), 255]);
+    }
+}
@@ -6,12 +6,18 @@ use std::fmt;
 use std::fmt::Display;
 use std::collections::HashMap;
 
-use ptsd::{ColorResources, IconResources, FontResources, utils::Assets};
+use prism::Context;
+use prism::event::Event;
+use prism::layout::Area;
+
+use crate::locale::Locale;
+
+use ptsd::{ColorResources, IconResources, FontResources, TextSize, utils::Assets};
 
 pub use ptsd::Color;
 
 #[derive(Debug, Clone)]
-pub struct Theme(ptsd::Theme, BrandResources);
+pub struct Theme(ptsd::Theme, BrandResources, Palette);
 impl Theme {
     pub fn from(assets: &Dir<'static>, color: Color) -> Self { 
         let (theme, is_dark) = ptsd::Theme::from(assets, color);
@@ -24,9 +30,152 @@ impl Theme {
     fn new(assets: &Dir<'static>, mut inner: ptsd::Theme, is_dark: bool, color: Color) -> Self {
         Icons::map(&mut inner.icons);
         Button::map(&mut inner.colors, is_dark, color);
-        Theme(inner, BrandResources::new(assets))
+        OnStatus::map(&mut inner.colors);
+        Theme(inner, BrandResources::new(assets), Palette { brand: color, is_dark, font_sizes: HashMap::new(), icons: HashMap::new(), locale: Locale::default(), has_locale: false })
+    }
+
+    /// A maximum-contrast variant: pure black/white surfaces, solid outlines on every
+    /// interactive state and status colors that clear WCAG AAA against the background.
+    pub fn high_contrast(assets: &Dir<'static>, color: Color, is_dark: bool) -> Self {
+        let inner = match is_dark {
+            true => ptsd::Theme::dark(assets, color),
+            false => ptsd::Theme::light(assets, color),
+        };
+        let mut theme = Theme::new(assets, inner, is_dark, color);
+        HighContrast::map(&mut theme.0.colors, is_dark);
+        theme
+    }
+
+    /// A variant whose status colors stay distinguishable for the given color
+    /// vision deficiency, and whose disabled buttons differ by lightness rather than hue.
+    pub fn color_blind(assets: &Dir<'static>, color: Color, vision: ColorVision) -> Self {
+        let mut theme = Theme::from(assets, color);
+        vision.map(&mut theme.0.colors, theme.2.is_dark);
+        theme
+    }
+
+    /// Builds a theme from a design-token set, starting from the light or dark
+    /// defaults for the brand color and applying every override on top.
+    pub fn from_tokens(assets: &Dir<'static>, tokens: &ThemeTokens) -> Result<Self, String> {
+        let brand = parse_hex(&tokens.brand)?;
+        let mut theme = match tokens.dark {
+            Some(true) => Theme::dark(assets, brand),
+            Some(false) => Theme::light(assets, brand),
+            None => Theme::from(assets, brand),
+        };
+        tokens.colors.apply(&mut theme.0.colors)?;
+        tokens.buttons.apply(&mut theme.0.colors)?;
+
+        for (key, size) in &tokens.font_sizes {
+            if !SIZES.iter().any(|(k, _)| k == key) { return Err(format!("Unknown font size '{key}'")); }
+            theme.2.font_sizes.insert(key.to_string(), *size);
+        }
+
+        for (key, name) in &tokens.icons {
+            let icon = key.parse::<Icons>()?;
+            theme.0.icons.insert(icon, name.as_str());
+            theme.2.icons.insert(icon, name.to_string());
+        }
+
+        Ok(theme)
+    }
+
+    pub fn from_toml(assets: &Dir<'static>, source: &str) -> Result<Self, String> {
+        let tokens: ThemeTokens = toml::from_str(source).map_err(|e| e.to_string())?;
+        Theme::from_tokens(assets, &tokens)
+    }
+
+    pub fn from_json(assets: &Dir<'static>, source: &str) -> Result<Self, String> {
+        let tokens: ThemeTokens = serde_json::from_str(source).map_err(|e| e.to_string())?;
+        Theme::from_tokens(assets, &tokens)
+    }
+
+    /// Exports the fully resolved theme, so the file round-trips through [`Theme::from_tokens`].
+    pub fn tokens(&self) -> ThemeTokens {
+        ThemeTokens {
+            brand: to_hex(self.2.brand),
+            dark: Some(self.2.is_dark),
+            colors: ColorTokens::from(&self.0.colors),
+            buttons: ButtonTokens::from(&self.0.colors),
+            font_sizes: SIZES.iter().map(|(key, size)| (key.to_string(), self.font_size(*size))).collect(),
+            icons: self.2.icons.iter().map(|(icon, name)| (icon.to_string(), name.clone())).collect(),
+        }
+    }
+
+    pub fn to_toml(&self) -> Result<String, String> {
+        toml::to_string_pretty(&self.tokens()).map_err(|e| e.to_string())
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(&self.tokens()).map_err(|e| e.to_string())
+    }
+
+    /// Resolves a text size, honoring any `font_sizes` token override.
+    pub fn font_size(&self, size: TextSize) -> f32 {
+        let key = SIZES.iter().find(|(_, s)| std::mem::discriminant(s) == std::mem::discriminant(&size)).map(|(key, _)| *key);
+        key.and_then(|key| self.2.font_sizes.get(key).copied()).unwrap_or_else(|| self.0.fonts.get_size(size))
     }
 
+    /// Checks every foreground/background pair the components draw together
+    /// against the WCAG 2.1 contrast thresholds.
+    ///
+    /// ```rust
+    /// for check in theme.audit().failures(WcagLevel::AA) {
+    ///     println!("{check}");
+    /// }
+    /// ```
+    pub fn audit(&self) -> ContrastReport {
+        let colors = &self.0.colors;
+        let page = colors.get(ptsd::Background::Primary);
+        let mut checks = Vec::new();
+
+        for variant in [Variant::Primary, Variant::Secondary, Variant::Ghost] {
+            for state in [State::Default, State::Hover, State::Pressed, State::Disabled] {
+                let scheme = ButtonColorScheme::from(colors, variant, state);
+                let name = format!("{} label on background ({})", variant, state);
+                checks.push(ContrastCheck::new(name, scheme.label, blend(scheme.background, page)));
+            }
+        }
+
+        let backgrounds = [("Background::Primary", page), ("Background::Secondary", colors.get(ptsd::Background::Secondary))];
+        let styles = [
+            ("TextStyle::Heading", colors.get(ptsd::Text::Heading)),
+            ("TextStyle::Primary", colors.get(ptsd::Text::Primary)),
+            ("TextStyle::Secondary", colors.get(ptsd::Text::Secondary)),
+            ("TextStyle::Error", colors.get(ptsd::Status::Danger)),
+        ];
+
+        for (bg_name, bg) in backgrounds {
+            for (fg_name, fg) in styles {
+                checks.push(ContrastCheck::new(format!("{fg_name} on {bg_name}"), fg, blend(bg, page)));
+            }
+        }
+
+        let text = colors.get(ptsd::Text::Primary);
+        checks.push(ContrastCheck::new("Sent message on Brand".to_string(), text, blend(colors.get(ptsd::Brand), page)));
+        checks.push(ContrastCheck::new("Received message on Background::Secondary".to_string(), text, blend(colors.get(ptsd::Background::Secondary), page)));
+
+        ContrastReport(checks)
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.2.locale = locale;
+        self.2.has_locale = true;
+        self
+    }
+
+    /// Whether the locale was set with [`Theme::with_locale`] rather than left at the en-US default.
+    pub fn has_locale(&self) -> bool {self.2.has_locale}
+
+    pub fn locale(&self) -> &Locale {&self.2.locale}
+
+    /// Looks up a user-facing string in the theme's locale.
+    pub fn tr(&self, key: &str) -> String {self.2.locale.translate(key, &[])}
+    pub fn tr_args(&self, key: &str, args: &[(&str, &str)]) -> String {self.2.locale.translate(key, args)}
+
+    pub fn is_dark(&self) -> bool {self.2.is_dark}
+    pub fn brand_color(&self) -> Color {self.2.brand}
+
     pub fn colors(&self) -> &ColorResources {&self.0.colors}
     pub fn icons(&self) -> &IconResources {&self.0.icons}
     pub fn fonts(&self) -> &FontResources {&self.0.fonts}
@@ -47,6 +196,323 @@ impl Default for Theme {
     }
 }
 
+/// Swaps the active [`Theme`] at runtime.
+///
+/// Emit this from anywhere (e.g. when the OS switches to dark mode) and every
+/// component re-resolves its colors, fonts and icons on the next `TickEvent`
+/// instead of the whole `Interface` being rebuilt. Components whose shapes are baked
+/// from the theme keep what they were built with in `#[skip]` fields and rebuild
+/// from it here. The current locale carries over unless the new theme sets its own
+/// with [`Theme::with_locale`].
+///
+/// ```rust
+/// ctx.emit(ThemeChanged(Theme::light(&assets, brand)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThemeChanged(pub Theme);
+
+impl Event for ThemeChanged {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: &[Area]) -> Vec<Option<Box<dyn Event>>> {
+        children.iter().map(|_| Some(self.clone() as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Palette {
+    brand: Color,
+    is_dark: bool,
+    font_sizes: HashMap<String, f32>,
+    icons: HashMap<Icons, String>,
+    locale: Locale,
+    has_locale: bool,
+}
+
+const SIZES: [(&str, TextSize); 10] = [
+    ("h1", TextSize::H1), ("h2", TextSize::H2), ("h3", TextSize::H3), ("h4", TextSize::H4), ("h5", TextSize::H5),
+    ("xl", TextSize::Xl), ("lg", TextSize::Lg), ("md", TextSize::Md), ("sm", TextSize::Sm), ("xs", TextSize::Xs),
+];
+
+fn parse_hex(value: &str) -> Result<Color, String> {
+    let hex = value.trim_start_matches('#');
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid color '{value}', expected #RRGGBB or #RRGGBBAA"));
+    }
+    let alpha = match hex.len() {
+        8 => u8::from_str_radix(&hex[6..8], 16).map_err(|e| e.to_string())?,
+        _ => 255,
+    };
+    Ok(Color::from_hex(&hex[..6], alpha))
+}
+
+fn to_hex(color: Color) -> String {
+    match color.3 {
+        255 => format!("#{:02x}{:02x}{:02x}", color.0, color.1, color.2),
+        a => format!("#{:02x}{:02x}{:02x}{:02x}", color.0, color.1, color.2, a),
+    }
+}
+
+/// A serializable design-token file describing a [`Theme`].
+///
+/// Every field except `brand` is optional; anything left out falls back to the
+/// defaults `Theme::light`/`Theme::dark` derive from the brand color.
+///
+/// ```toml
+/// brand = "#00a2ff"
+/// dark = true
+///
+/// [colors.background]
+/// primary = "#000000"
+///
+/// [buttons.primary.hover]
+/// background = "#0077cc"
+///
+/// [font_sizes]
+/// h1 = 52.0
+///
+/// [icons]
+/// send = "paper_plane"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeTokens {
+    pub brand: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dark: Option<bool>,
+    #[serde(default)]
+    pub colors: ColorTokens,
+    #[serde(default)]
+    pub buttons: ButtonTokens,
+    #[serde(default)]
+    pub font_sizes: HashMap<String, f32>,
+    #[serde(default)]
+    pub icons: HashMap<String, String>,
+}
+
+macro_rules! token_group {
+    ($name:ident { $($field:ident => $token:expr),* $(,)? }) => {
+        #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+        pub struct $name {
+            $(#[serde(default, skip_serializing_if = "Option::is_none")] pub $field: Option<String>,)*
+        }
+
+        impl $name {
+            fn from(resources: &ColorResources) -> Self {
+                $name { $($field: Some(to_hex(resources.get($token))),)* }
+            }
+
+            fn apply(&self, resources: &mut ColorResources) -> Result<(), String> {
+                $(if let Some(hex) = &self.$field { resources.insert($token, parse_hex(hex)?); })*
+                Ok(())
+            }
+        }
+    };
+}
+
+token_group!(BackgroundTokens { primary => ptsd::Background::Primary, secondary => ptsd::Background::Secondary });
+token_group!(TextTokens { heading => ptsd::Text::Heading, primary => ptsd::Text::Primary, secondary => ptsd::Text::Secondary });
+token_group!(OutlineTokens { primary => ptsd::Outline::Primary, secondary => ptsd::Outline::Secondary });
+token_group!(StatusTokens { success => ptsd::Status::Success, warning => ptsd::Status::Warning, danger => ptsd::Status::Danger });
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColorTokens {
+    #[serde(default)]
+    pub background: BackgroundTokens,
+    #[serde(default)]
+    pub text: TextTokens,
+    #[serde(default)]
+    pub outline: OutlineTokens,
+    #[serde(default)]
+    pub status: StatusTokens,
+}
+
+impl ColorTokens {
+    fn from(resources: &ColorResources) -> Self {
+        ColorTokens {
+            background: BackgroundTokens::from(resources),
+            text: TextTokens::from(resources),
+            outline: OutlineTokens::from(resources),
+            status: StatusTokens::from(resources),
+        }
+    }
+
+    fn apply(&self, resources: &mut ColorResources) -> Result<(), String> {
+        self.background.apply(resources)?;
+        self.text.apply(resources)?;
+        self.outline.apply(resources)?;
+        self.status.apply(resources)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ButtonTokens {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary: Option<ButtonSetTokens>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary: Option<ButtonSetTokens>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ghost: Option<ButtonSetTokens>,
+}
+
+impl ButtonTokens {
+    fn from(resources: &ColorResources) -> Self {
+        ButtonTokens {
+            primary: Some(ButtonSetTokens::from(resources, Variant::Primary)),
+            secondary: Some(ButtonSetTokens::from(resources, Variant::Secondary)),
+            ghost: Some(ButtonSetTokens::from(resources, Variant::Ghost)),
+        }
+    }
+
+    fn apply(&self, resources: &mut ColorResources) -> Result<(), String> {
+        let sets = [(Variant::Primary, &self.primary), (Variant::Secondary, &self.secondary), (Variant::Ghost, &self.ghost)];
+        for (variant, set) in sets {
+            if let Some(set) = set { set.apply(resources, variant)?; }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ButtonSetTokens {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<ButtonSchemeTokens>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hover: Option<ButtonSchemeTokens>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pressed: Option<ButtonSchemeTokens>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<ButtonSchemeTokens>,
+}
+
+impl ButtonSetTokens {
+    fn from(resources: &ColorResources, variant: Variant) -> Self {
+        let scheme = |state| Some(ButtonSchemeTokens::from(resources, variant, state));
+        ButtonSetTokens {
+            default: scheme(State::Default),
+            hover: scheme(State::Hover),
+            pressed: scheme(State::Pressed),
+            disabled: scheme(State::Disabled),
+        }
+    }
+
+    fn apply(&self, resources: &mut ColorResources, variant: Variant) -> Result<(), String> {
+        let schemes = [(State::Default, &self.default), (State::Hover, &self.hover), (State::Pressed, &self.pressed), (State::Disabled, &self.disabled)];
+        for (state, scheme) in schemes {
+            if let Some(scheme) = scheme { scheme.apply(resources, variant, state)?; }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ButtonSchemeTokens {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outline: Option<String>,
+}
+
+impl ButtonSchemeTokens {
+    fn from(resources: &ColorResources, variant: Variant, state: State) -> Self {
+        let scheme = ButtonColorScheme::from(resources, variant, state);
+        ButtonSchemeTokens {
+            background: Some(to_hex(scheme.background)),
+            label: Some(to_hex(scheme.label)),
+            outline: Some(to_hex(scheme.outline)),
+        }
+    }
+
+    fn apply(&self, resources: &mut ColorResources, variant: Variant, state: State) -> Result<(), String> {
+        let slots = [(Slot::Background, &self.background), (Slot::Label, &self.label), (Slot::Outline, &self.outline)];
+        for (slot, hex) in slots {
+            if let Some(hex) = hex { resources.insert(Button(variant, state, slot), parse_hex(hex)?); }
+        }
+        Ok(())
+    }
+}
+
+/// WCAG 2.1 conformance levels for normal-sized text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WcagLevel { AA, AAA }
+
+impl WcagLevel {
+    pub fn minimum_ratio(&self) -> f32 {
+        match self {
+            WcagLevel::AA => 4.5,
+            WcagLevel::AAA => 7.0,
+        }
+    }
+}
+
+/// A single foreground/background pair checked by [`Theme::audit`].
+#[derive(Debug, Clone)]
+pub struct ContrastCheck {
+    pub name: String,
+    pub foreground: Color,
+    pub background: Color,
+    pub ratio: f32,
+}
+
+impl ContrastCheck {
+    fn new(name: String, foreground: Color, background: Color) -> Self {
+        let foreground = blend(foreground, background);
+        ContrastCheck { name, foreground, background, ratio: contrast_ratio(foreground, background) }
+    }
+
+    pub fn passes(&self, level: WcagLevel) -> bool {self.ratio >= level.minimum_ratio()}
+}
+
+impl Display for ContrastCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match (self.passes(WcagLevel::AAA), self.passes(WcagLevel::AA)) {
+            (true, _) => "AAA",
+            (false, true) => "AA",
+            _ => "FAIL",
+        };
+        write!(f, "{:<5} {:>5.2}:1  {} ({} on {})", level, self.ratio, self.name, to_hex(self.foreground), to_hex(self.background))
+    }
+}
+
+/// The result of [`Theme::audit`].
+#[derive(Debug, Clone)]
+pub struct ContrastReport(pub Vec<ContrastCheck>);
+
+impl ContrastReport {
+    pub fn checks(&self) -> &[ContrastCheck] {&self.0}
+
+    pub fn failures(&self, level: WcagLevel) -> Vec<&ContrastCheck> {
+        self.0.iter().filter(|check| !check.passes(level)).collect()
+    }
+
+    pub fn passes(&self, level: WcagLevel) -> bool {self.0.iter().all(|check| check.passes(level))}
+}
+
+impl Display for ContrastReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|check| writeln!(f, "{check}"))
+    }
+}
+
+/// Composites a possibly translucent color over an opaque backdrop.
+fn blend(color: Color, backdrop: Color) -> Color {
+    let alpha = color.3 as f32 / 255.0;
+    let mix = |c: u8, b: u8| (c as f32 * alpha + b as f32 * (1.0 - alpha)).round() as u8;
+    Color(mix(color.0, backdrop.0), mix(color.1, backdrop.1), mix(color.2, backdrop.2), 255)
+}
+
+fn relative_luminance(color: Color) -> f32 {
+    let channel = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    };
+    0.2126 * channel(color.0) + 0.7152 * channel(color.1) + 0.0722 * channel(color.2)
+}
+
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
 #[derive(Clone, Debug)]
 pub struct BrandResources {
     pub wordmark: Arc<RgbaImage>,
@@ -115,6 +581,126 @@ impl BrandResources {
 }
 
 
+/// The color drawn on top of a `Status` fill, such as the icon inside a success flair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OnStatus { Success, Warning, Danger }
+impl Display for OnStatus {fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {write!(f, "OnStatus::{}", match self {
+    OnStatus::Success => "Success",
+    OnStatus::Warning => "Warning",
+    OnStatus::Danger => "Danger",
+})}}
+
+impl OnStatus {
+    fn map(resources: &mut ColorResources) {
+        resources.insert(OnStatus::Success, Color::WHITE);
+        resources.insert(OnStatus::Warning, Color::WHITE);
+        resources.insert(OnStatus::Danger, Color::WHITE);
+    }
+
+    fn contrast(resources: &mut ColorResources) {
+        resources.insert(OnStatus::Success, resources.get(ptsd::Status::Success).contrasted());
+        resources.insert(OnStatus::Warning, resources.get(ptsd::Status::Warning).contrasted());
+        resources.insert(OnStatus::Danger, resources.get(ptsd::Status::Danger).contrasted());
+    }
+}
+
+/// Color vision deficiencies supported by [`Theme::color_blind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ColorVision {
+    /// Reduced green sensitivity; red and green are confused.
+    Deuteranopia,
+    /// Reduced red sensitivity; red and green are confused and reds look dark.
+    Protanopia,
+    /// Reduced blue sensitivity; blue and green, and yellow and violet are confused.
+    Tritanopia,
+}
+
+impl ColorVision {
+    /// Status colors from the Okabe-Ito palette, picked so success and danger
+    /// never rely on the red/green (or blue/yellow) axis the user cannot see.
+    fn status(&self) -> (Color, Color, Color) {
+        match self {
+            ColorVision::Deuteranopia | ColorVision::Protanopia => (
+                Color::from_hex("#0072B2", 255),
+                Color::from_hex("#E69F00", 255),
+                Color::from_hex("#D55E00", 255),
+            ),
+            ColorVision::Tritanopia => (
+                Color::from_hex("#009E73", 255),
+                Color::from_hex("#CC79A7", 255),
+                Color::from_hex("#D55E00", 255),
+            ),
+        }
+    }
+
+    fn map(&self, resources: &mut ColorResources, is_dark: bool) {
+        let (success, warning, danger) = self.status();
+        resources.insert(ptsd::Status::Success, success);
+        resources.insert(ptsd::Status::Warning, warning);
+        resources.insert(ptsd::Status::Danger, danger);
+        OnStatus::contrast(resources);
+
+        let (background, label) = match is_dark {
+            true => (Color::from_hex("#262322", 255), Color::from_hex("#8c8585", 255)),
+            false => (Color::from_hex("#e6e6e6", 255), Color::from_hex("#6b6666", 255)),
+        };
+        for variant in [Variant::Primary, Variant::Secondary, Variant::Ghost] {
+            resources.insert(Button(variant, State::Disabled, Slot::Background), background);
+            resources.insert(Button(variant, State::Disabled, Slot::Label), label);
+        }
+    }
+}
+
+struct HighContrast;
+
+impl HighContrast {
+    fn map(resources: &mut ColorResources, is_dark: bool) {
+        use Slot::*;
+        use State::*;
+        use Variant::*;
+
+        let hex = |dark: &str, light: &str| Color::from_hex(if is_dark { dark } else { light }, 255);
+        let (background, foreground) = match is_dark {
+            true => (Color::BLACK, Color::WHITE),
+            false => (Color::WHITE, Color::BLACK),
+        };
+        let surface = hex("#1a1a1a", "#f0f0f0");
+        let muted = hex("#d0d0d0", "#333333");
+        let disabled = hex("#a6a6a6", "#595959");
+
+        resources.insert(ptsd::Background::Primary, background);
+        resources.insert(ptsd::Background::Secondary, surface);
+        resources.insert(ptsd::Text::Heading, foreground);
+        resources.insert(ptsd::Text::Primary, foreground);
+        resources.insert(ptsd::Text::Secondary, muted);
+        resources.insert(ptsd::Outline::Primary, foreground);
+        resources.insert(ptsd::Outline::Secondary, muted);
+        resources.insert(ptsd::Status::Success, hex("#5ce65c", "#005c00"));
+        resources.insert(ptsd::Status::Warning, hex("#ffd60a", "#6b4500"));
+        resources.insert(ptsd::Status::Danger, hex("#ff8080", "#a3001b"));
+        OnStatus::contrast(resources);
+
+        let mut scheme = |variant, state, fill: Color, label: Color, outline: Color| {
+            resources.insert(Button(variant, state, Background), fill);
+            resources.insert(Button(variant, state, Label), label);
+            resources.insert(Button(variant, state, Outline), outline);
+        };
+
+        scheme(Primary, Default, foreground, background, Color::TRANSPARENT);
+        scheme(Primary, Hover, muted, background, foreground);
+        scheme(Primary, Pressed, muted, background, foreground);
+        scheme(Primary, Disabled, Color::TRANSPARENT, disabled, disabled);
+
+        for variant in [Secondary, Ghost] {
+            let outline = if variant == Ghost { Color::TRANSPARENT } else { foreground };
+            scheme(variant, Default, Color::TRANSPARENT, foreground, outline);
+            scheme(variant, Hover, surface, foreground, foreground);
+            scheme(variant, Pressed, surface, foreground, foreground);
+            scheme(variant, Disabled, Color::TRANSPARENT, disabled, if variant == Ghost { Color::TRANSPARENT } else { disabled });
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct ButtonColors {
     pub primary: ButtonColorSet,
@@ -277,6 +863,24 @@ macro_rules! icons {
             pub fn map(icons: &mut IconResources) {
                 $(icons.insert(Icons::$variant, $name);)*
             }
+
+            /// A readable name such as "Down arrow", used to label icon-only buttons.
+            pub fn label(&self) -> String {
+                let name = self.to_string().replace('_', " ");
+                let mut chars = name.chars();
+                chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+            }
+        }
+
+        impl std::str::FromStr for Icons {
+            type Err = String;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                match name {
+                    $($name => Ok(Icons::$variant),)*
+                    _ => Err(format!("Unknown icon '{name}'")),
+                }
+            }
         }
 
         impl std::fmt::Display for Icons {
@@ -305,12 +909,14 @@ icons! {
     Capslock => "capslock",
     Check => "check",
     Checkmark => "checkmark",
+    Clock => "clock",
     Close => "close",
     Copy => "copy",
     Credential => "credential",
     Delete => "delete",
     Discord => "discord",
     Door => "door",
+    DoubleTick => "double_tick",
     DownArrow => "down_arrow",
     Down => "down",
     Edit => "edit",
@@ -318,10 +924,12 @@ icons! {
     Error => "error",
     Explore => "explore",
     Facebook => "facebook",
+    File => "file",
     Forward => "forward",
     Gif => "gif",
     Group => "group",
     Heart => "heart",
+    Hide => "hide",
     Home => "home",
     Infinite => "infinite",
     Info => "info",
@@ -347,6 +955,8 @@ icons! {
     Send => "send",
     Senior => "senior",
     Settings => "settings",
+    Show => "show",
+    Tick => "tick",
     Unblock => "unblock",
     Unboot => "unboot",
     Unchecked => "unchecked",
@@ -355,3 +965,88 @@ icons! {
     Warning => "warning",
     X => "x",
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assets() -> Dir<'static> {include_dir!("resources")}
+
+    #[test]
+    fn hex_colors_round_trip() {
+        for hex in ["#00a2ff", "#ffffff", "#12345680"] {
+            assert_eq!(to_hex(parse_hex(hex).unwrap()), hex);
+        }
+        assert!(parse_hex("#12345").is_err());
+        assert!(parse_hex("#zzzzzz").is_err());
+    }
+
+    #[test]
+    fn tokens_round_trip_through_toml_and_json() {
+        let theme = Theme::light(&assets(), Color::from_hex("#ff5500", 255));
+
+        // Compared as values, since font sizes and icons serialize in map order.
+        let toml = theme.to_toml().unwrap();
+        let reloaded = Theme::from_toml(&assets(), &toml).unwrap().to_toml().unwrap();
+        assert_eq!(toml::from_str::<toml::Value>(&reloaded).unwrap(), toml::from_str::<toml::Value>(&toml).unwrap());
+
+        let json = theme.to_json().unwrap();
+        let reloaded = Theme::from_json(&assets(), &json).unwrap().to_json().unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&reloaded).unwrap(), serde_json::from_str::<serde_json::Value>(&json).unwrap());
+    }
+
+    #[test]
+    fn tokens_override_the_brand_defaults() {
+        let source = r##"
+            brand = "#00a2ff"
+            dark = true
+
+            [colors.text]
+            primary = "#abcdef"
+
+            [font_sizes]
+            h1 = 40.0
+        "##;
+        let theme = Theme::from_toml(&assets(), source).unwrap();
+        assert!(theme.is_dark());
+        assert_eq!(to_hex(theme.colors().get(ptsd::Text::Primary)), "#abcdef");
+        assert_eq!(theme.font_size(TextSize::H1), 40.0);
+    }
+
+    #[test]
+    fn contrast_ratio_matches_wcag() {
+        assert!((contrast_ratio(Color::BLACK, Color::WHITE) - 21.0).abs() < 0.01);
+        assert_eq!(contrast_ratio(Color::BLACK, Color::WHITE), contrast_ratio(Color::WHITE, Color::BLACK));
+        assert_eq!(contrast_ratio(Color::WHITE, Color::WHITE), 1.0);
+        // #767676 is the lightest grey that passes AA on white.
+        let grey = contrast_ratio(Color::from_hex("767676", 255), Color::WHITE);
+        assert!(grey >= 4.5 && grey < 4.6);
+    }
+
+    #[test]
+    fn audit_blends_translucent_colors_over_the_page() {
+        let check = ContrastCheck::new("half white".to_string(), Color::from_hex("ffffff", 128), Color::BLACK);
+        assert_eq!(to_hex(check.foreground), "#808080");
+        assert!(!check.passes(WcagLevel::AA));
+    }
+
+    #[test]
+    fn audit_reports_failures_per_level() {
+        let mut theme = Theme::light(&assets(), Color::from_hex("#00a2ff", 255));
+        let page = theme.colors().get(ptsd::Background::Primary);
+        theme.colors_mut().insert(ptsd::Text::Secondary, page);
+
+        let report = theme.audit();
+        assert!(report.checks().iter().any(|check| check.name == "Sent message on Brand"));
+        assert!(report.failures(WcagLevel::AA).iter().any(|check| check.name == "TextStyle::Secondary on Background::Primary"));
+        assert!(!report.passes(WcagLevel::AA));
+        assert!(report.failures(WcagLevel::AA).len() <= report.failures(WcagLevel::AAA).len());
+        assert_eq!(report.to_string().lines().count(), report.checks().len());
+    }
+
+    #[test]
+    fn invalid_tokens_are_rejected() {
+        assert!(Theme::from_toml(&assets(), "brand = \"blue\"").is_err());
+        assert!(Theme::from_toml(&assets(), "brand = \"#00a2ff\"\n[font_sizes]\nhuge = 90.0").is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use prism::{Context, drawables};
+use prism::drawable::Component;
+use prism::event::{OnEvent, NamedKey};
+use prism::layout::{Offset, Stack};
+
+use pelican_ui::theme::{Theme, Icons};
+use pelican_ui::components::TextInput;
+use pelican_ui::interface::general::{Page, Header, Content};
+use pelican_ui::interface::navigation::{RootInfo, AppPage};
+use pelican_ui::interface::focus::FocusEvent;
+use pelican_ui::testing::Driver;
+
+#[derive(Debug, Component, Clone)]
+struct Form(Stack, Page);
+impl OnEvent for Form {}
+impl AppPage for Form {}
+impl Form {
+    fn new(theme: &Theme) -> Self {
+        let input = TextInput::new(theme, None, Some("Name"), Some("Your name..."), None, None);
+        let content = Content::new(Offset::Start, drawables![input], Box::new(|_| true));
+        Form(Stack::default(), Page::new(Header::home(theme, "Form", None), content, None))
+    }
+}
+
+/// A driver showing a single [`Form`], nothing focused yet.
+fn form() -> Driver {
+    Driver::new((390.0, 844.0), &Theme::default(), |_: &mut Context, theme: &Theme| vec![
+        RootInfo::icon(Icons::Home, "Home", Box::new(Form::new(theme))),
+    ])
+}
+
+/// A driver showing a single [`Form`] with its name field focused.
+fn focused_form() -> Driver {
+    let mut driver = form();
+    driver.key(NamedKey::Tab);
+    driver
+}
+
+#[test]
+fn tab_focuses_the_first_input() {
+    let mut driver = form();
+    driver.watch::<FocusEvent>();
+    driver.key(NamedKey::Tab);
+
+    assert!(driver.emitted::<FocusEvent>().iter().any(|e| matches!(e, FocusEvent::Set(0, _))));
+    assert!(driver.log().iter().any(|e| e.contains("Focused(true)")));
+}
+
+#[test]
+fn typed_text_reaches_the_focused_input() {
+    let mut driver = focused_form();
+    driver.type_text("Ada Lovelace");
+    assert_eq!(driver.text_input("Name").unwrap().value(), "Ada Lovelace");
+
+    driver.key(NamedKey::Backspace);
+    assert_eq!(driver.text_input("Name").unwrap().value(), "Ada Lovelac");
+}
+
+#[test]
+fn typing_after_accented_letters() {
+    let mut driver = focused_form();
+    // Accent popups insert multi-byte letters; the caret must stay on a character boundary.
+    driver.type_text("Zoë Ångström");
+    driver.key(NamedKey::ArrowLeft);
+    driver.type_text("ö");
+    assert_eq!(driver.text_input("Name").unwrap().value(), "Zoë Ångströöm");
+
+    driver.key(NamedKey::Backspace);
+    driver.key(NamedKey::Backspace);
+    assert_eq!(driver.text_input("Name").unwrap().value(), "Zoë Ångstrm");
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use pelican_ui::theme::Color;
+use pelican_ui::components::Rectangle;
+use pelican_ui::testing::{Snapshot, UPDATE_SNAPSHOTS};
+
+/// A scratch directory for goldens written by one test.
+fn scratch(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("pelican_ui_snapshot_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+#[test]
+fn renders_shapes_over_the_background() {
+    let card = Rectangle::new(Color::from_hex("ff0000", 255), 0.0, None);
+    let image = Snapshot::new(8, 4).background(Color::WHITE).render(&card);
+
+    assert_eq!(image.dimensions(), (8, 4));
+    assert!(image.pixels().all(|pixel| pixel.0 == [255, 0, 0, 255]));
+}
+
+#[test]
+fn rounded_corners_leave_the_background_showing() {
+    let card = Rectangle::new(Color::from_hex("ff0000", 255), 8.0, None);
+    let image = Snapshot::new(16, 16).background(Color::WHITE).render(&card);
+
+    assert_eq!(image.get_pixel(0, 0).0, [255, 255, 255, 255]);
+    assert_eq!(image.get_pixel(8, 8).0, [255, 0, 0, 255]);
+}
+
+#[test]
+fn missing_goldens_fail() {
+    if std::env::var_os(UPDATE_SNAPSHOTS).is_some() { return; }
+    let snapshot = Snapshot::new(4, 4).directory(scratch("missing"));
+    let card = Rectangle::new(Color::WHITE, 0.0, None);
+
+    assert_eq!(snapshot.check("card", &card), Err("no golden for 'card'".to_string()));
+}
+
+#[test]
+fn goldens_match_within_tolerance() {
+    if std::env::var_os(UPDATE_SNAPSHOTS).is_some() { return; }
+    let directory = scratch("compare");
+    let snapshot = Snapshot::new(4, 4).directory(&directory);
+
+    let golden = snapshot.render(&Rectangle::new(Color::from_hex("336699", 255), 0.0, None));
+    golden.save(directory.join("card.png")).unwrap();
+
+    assert_eq!(snapshot.check("card", &Rectangle::new(Color::from_hex("336699", 255), 0.0, None)), Ok(()));
+    assert_eq!(snapshot.check("card", &Rectangle::new(Color::from_hex("33669b", 255), 0.0, None)), Ok(()));
+
+    let error = snapshot.check("card", &Rectangle::new(Color::from_hex("993366", 255), 0.0, None)).unwrap_err();
+    assert!(error.contains("differs in 16 pixels"), "{error}");
+    assert!(directory.join("card.actual.png").exists());
+}